@@ -170,6 +170,12 @@ provision_transmutation!
 // Provide access to the current Construct instance.
 define_instances! { ConstructInstances for Construct : Fn[construct, construct_mut] -> Construct }
 
+// Register IConstruct and Construct with the library that lists this module.
+register_types!
+{
+    types [dyn IConstruct = ICONSTRUCT_TYPE_UUID, Construct = CONSTRUCT_TYPE_UUID]
+}
+
 // *** Internal (i.e. public in crate) constants ***
 
 //
@@ -190,6 +196,7 @@ pub(crate) const CONSTRUCT_TYPE_UUID: u128 = 27615436219347008330783863845158382
 /// Parameters:
 /// * $trait_name (ident): The name to assign to the trait.
 /// * $current_type (ty): The concrete type for which the trait is being implemented.
+/// * $generic (ident): The generic type parameters of $current_type, if any.
 /// * $get (ident): The name of the method used to obtain an immutable reference to the concrete
 ///   (Construct) instance.
 /// * $get_mut (ident): The name of the method used to obtain a mutable reference to the concrete
@@ -234,6 +241,17 @@ pub(crate) const CONSTRUCT_TYPE_UUID: u128 = 27615436219347008330783863845158382
 ///      }
 /// ```
 ///
+///  To implement the parent type's trait for a generic type (generic parameters are implicitly
+///   'static):
+///
+/// ```
+///      define_instances!
+///      {
+///          ConstructInstances for<T> Property<T> : Fn[construct, construct_mut] ->
+///            Construct (construct)
+///      }
+/// ```
+///
 ///  To implement a further ancestor type's trait for a type that encompasses that existing type
 ///   and accesses it through "self.car.vehicle.construct[_mut]()" (or deeper):
 ///
@@ -249,10 +267,50 @@ pub(crate) const CONSTRUCT_TYPE_UUID: u128 = 27615436219347008330783863845158382
 /// * The recommended format for $trait_name is <concrete type>Instances, e.g. VehicleInstances.
 /// * To evade Rust compiler warnings, the recommended format for $get and $get_mut are <snake case
 ///    of concrete type> and <snake case of concrete type>_mut.
+/// * When a $parent_field (without further method calls) is specified, the Encompasses trait is
+///   also implemented for $current_type, exposing its direct parent to Necromancer (e.g. for
+///   as_ancestor::<T>() calls).
+///   * As such, that form should only be used for the type's *direct* parent.
 ///
 #[macro_export]
 macro_rules! define_instances
 {
+    // Implementation note: The generic arms must precede the non-generic arms, as a failed attempt
+    //                      to parse "for<T> ..." as a type would otherwise abort expansion.
+
+    // When a generic type is defined within the current module's parent module...
+    {
+        $trait_name: ident for<$($generic: ident),+> $current_type: ty :
+          Fn[$get: ident, $get_mut: ident] -> $expressed_type: ident ($parent_field: ident)
+    } =>
+    {
+        impl<$($generic: 'static),+> $trait_name for $current_type
+        {
+            fn $get(&self) -> &$expressed_type { &(self.$parent_field) }
+            fn $get_mut(&mut self) -> &mut $expressed_type { &mut(self.$parent_field) }
+        }
+        impl<$($generic: 'static),+> Encompasses<$expressed_type> for $current_type
+        {
+            fn encompassed(&self) -> &$expressed_type { &(self.$parent_field) }
+            fn encompassed_mut(&mut self) -> &mut $expressed_type { &mut(self.$parent_field) }
+        }
+    };
+
+    // When a generic type is defined within the current module's grandparent or earlier ancestor
+    //  module...
+    {
+        $trait_name: ident for<$($generic: ident),+> $current_type: ty :
+          Fn[$get: ident, $get_mut: ident] -> $expressed_type: ident ($parent_field: ident
+            { $(.$method: ident)* } { $(.$method_mut: ident)* })
+    } =>
+    {
+        impl<$($generic: 'static),+> $trait_name for $current_type
+        {
+            fn $get(&self) -> &$expressed_type { self.$parent_field$(.$method())* }
+            fn $get_mut(&mut self) -> &mut $expressed_type { self.$parent_field$(.$method_mut())* }
+        }
+    };
+
     // Within the module in which the type is defined...
     {
         $trait_name: ident for $current_type: ty : Fn[$get: ident, $get_mut: ident] ->
@@ -322,6 +380,11 @@ macro_rules! define_instances
             fn $get(&self) -> &$expressed_type { &(self.$parent_field) }
             fn $get_mut(&mut self) -> &mut $expressed_type { &mut(self.$parent_field) }
         }
+        impl Encompasses<$expressed_type> for $current_type
+        {
+            fn encompassed(&self) -> &$expressed_type { &(self.$parent_field) }
+            fn encompassed_mut(&mut self) -> &mut $expressed_type { &mut(self.$parent_field) }
+        }
     };
 
     // When type is defined within the current module's grandparent or earlier ancestor module (and
@@ -351,5 +414,9 @@ use crate::define_instances;
 use crate::impl_concrete_divinator;
 use crate::provision_transmutation;
 use crate::provision_upcasting;
-use crate::_infrastructure::thaumaturgy::{ Divinator, LibraryIdentifier, TypeIdentifier,
-                                           TypeRegistry };
+use crate::register_types;
+use crate::_infrastructure::thaumaturgy::{ DerivedCasts, Divinator, LibraryIdentifier, Lineage,
+                                           ParentAccessors, Registered, RegistryError,
+                                           TypeIdentifier, TypeKind, TypeRegistry };
+use std::any::TypeId;
+use std::sync::OnceLock;
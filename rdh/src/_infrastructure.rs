@@ -18,4 +18,8 @@
 // Contains public module declarations for RDH core infrastructure.
 //
 
+pub mod initialization;
+pub mod manifest;
+pub mod reader_writer_lock;
 pub mod thaumaturgy;
+pub mod uuid;
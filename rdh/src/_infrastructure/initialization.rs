@@ -0,0 +1,542 @@
+// ************************************************************************************************
+// Copyright 2023 Gene DeClark and Contributors within this file's version control history
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+// ************************************************************************************************
+
+//!
+//! initialization.rs
+//!
+//! Library: The struct through which each RDH library declares the libraries it depends upon and
+//!          the function that registers its own types, so that the RDH library stack forms a
+//!          dependency graph that initializes itself in order, exactly once.
+//!
+//! Notes:
+//! * Each RDH library declares a public static Library via provision_library!, listing the
+//!   Library statics of the libraries it directly depends upon and the modules whose types it
+//!   registers (each via register_types!), e.g.:
+//!
+//! ```ignore
+//!   provision_library!
+//!   {
+//!       pub static LIBRARY: "vehicles" (VEHICLES_UUID)
+//!         version env!("CARGO_PKG_VERSION"), requires rdh "0.5.0"
+//!         extends [rdh::LIBRARY]
+//!       {
+//!           _hierarchy::vehicle, _hierarchy::car
+//!       }
+//!   }
+//! ```
+//!
+//! * Initializing any Library first initializes each of its parents (depth first, in declaration
+//!   order), then registers its own types. Each Library registers its types only once, no matter
+//!   how many times (or through how many dependents) it's initialized, so a library shared by
+//!   several others (e.g. the apex of a diamond-shaped dependency graph) is no longer a problem.
+//! * The application therefore makes a single call: Library::init() on the library it consumes,
+//!   or init_all() when it consumes several sibling libraries.
+//! * Each Library also declares its version and the minimum rdh version it's compatible with.
+//!   Initialization rejects a library built for an incompatible rdh version, and records each
+//!   library's metadata within the TypeRegistry (see TypeRegistry::library_record_of()), so that
+//!   diagnostics may name libraries rather than quote their UUIDs.
+//!
+//! THIS IS A WORK IN PROGRESS.
+//!   Although broad strokes will be retained, at this time, everything is subject to change
+//!   without notice.
+//!
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Library struct
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Library: Describes an RDH library's place within the library dependency graph, along with its
+///          metadata and the function that registers its types.
+///
+pub struct Library
+{
+    // The library's name, reported within any InitError.
+    name: &'static str,
+
+    // The library's version, e.g. "0.5.0".
+    version: &'static str,
+
+    // The unique identifier of the library.
+    uuid: u128,
+
+    // The earliest rdh version with which the library is compatible.
+    minimum_rdh_version: &'static str,
+
+    // The libraries this library directly depends upon.
+    parents: &'static [&'static Library],
+
+    // The function that registers this library's types and downcast handlers.
+    register: fn(),
+
+    // Guards register so that it's invoked only once.
+    once: Once
+}
+impl Library
+{
+    // *** Public functions ***
+
+    ///
+    /// new: Creates a new, uninitialized instance of the Library struct.
+    ///
+    /// Parameters:
+    /// * name (&'static str): The library's name.
+    /// * version (&'static str): The library's version, e.g. env!("CARGO_PKG_VERSION").
+    /// * uuid (u128): The unique identifier of the library, with which its types are registered.
+    /// * minimum_rdh_version (&'static str): The earliest rdh version with which the library is
+    ///                                       compatible, e.g. "0.5.0".
+    /// * parents (&'static [&'static Library]): The libraries this library directly depends upon.
+    /// * register (fn()): The function that registers this library's types and downcast handlers.
+    ///                    It must not initialize any other library; list those within parents
+    ///                    instead.
+    ///
+    /// Notes:
+    /// * Typically invoked via provision_library!.
+    ///
+    pub const fn new(name: &'static str,
+                     version: &'static str,
+                     uuid: u128,
+                     minimum_rdh_version: &'static str,
+                     parents: &'static [&'static Library],
+                     register: fn()) -> Library
+    {
+        Library
+        {
+            name,
+            version,
+            uuid,
+            minimum_rdh_version,
+            parents,
+            register,
+            once: Once::new()
+        }
+    }
+
+    ///
+    /// name: Retrieves this library's name.
+    ///
+    pub fn name(&self) -> &'static str
+    {
+        self.name
+    }
+
+    ///
+    /// version: Retrieves this library's version.
+    ///
+    pub fn version(&self) -> &'static str
+    {
+        self.version
+    }
+
+    ///
+    /// identifier: Retrieves this library's identifier.
+    ///
+    pub fn identifier(&self) -> LibraryIdentifier
+    {
+        LibraryIdentifier::from_uuid(self.uuid)
+    }
+
+    ///
+    /// minimum_rdh_version: Retrieves the earliest rdh version with which this library is
+    ///                      compatible.
+    ///
+    pub fn minimum_rdh_version(&self) -> &'static str
+    {
+        self.minimum_rdh_version
+    }
+
+    ///
+    /// parents: Retrieves the libraries this library directly depends upon.
+    ///
+    pub fn parents(&self) -> &'static [&'static Library]
+    {
+        self.parents
+    }
+
+    ///
+    /// is_initialized: Determines whether this library has been initialized.
+    ///
+    /// Notes:
+    /// * While a RegistryScope is current on this thread, determines whether this library has been
+    ///   initialized within that scope.
+    ///
+    pub fn is_initialized(&self) -> bool
+    {
+        match RegistryScope::is_active()
+        {
+            true => matches!(TypeRegistry::try_library_record_of(self.identifier()), Ok(Some(_))),
+            false => self.once.is_completed()
+        }
+    }
+
+    ///
+    /// init: Initializes this library, first initializing each library it depends upon.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_init() expresses an error.
+    /// * If any library's registration function panics.
+    ///
+    /// Notes:
+    /// * This function is a thin wrapper around try_init().
+    ///
+    pub fn init(&'static self)
+    {
+        if let Err(error) = self.try_init()
+        {
+            panic!("Library::init(): {}", error);
+        }
+    }
+
+    ///
+    /// try_init: Attempts to initialize this library, first initializing each library it depends
+    ///           upon.
+    ///
+    /// Expresses:
+    /// * If successful (or if already initialized), Ok(()).
+    /// * If this library depends upon itself (directly or otherwise),
+    ///   Err(InitError::DependencyCycle) naming the first library reached twice. Libraries
+    ///   initialized before the cycle was detected remain initialized.
+    /// * If this library (or any library it depends upon) isn't compatible with the rdh version in
+    ///   use, Err(InitError::IncompatibleVersion) naming the first such library.
+    ///
+    /// Panics:
+    /// * If another library with the same UUID has already been initialized.
+    /// * If any library's registration function panics.
+    ///
+    /// Notes:
+    /// * Safe to call any number of times, from any number of threads. Threads that request a
+    ///   library being initialized by another thread wait until its initialization completes.
+    /// * A library is compatible with the rdh version in use (RDH_VERSION) when that version is
+    ///   no earlier than its minimum_rdh_version, and shares its leftmost nonzero version
+    ///   component (as with Cargo's default "caret" requirements), e.g. a library requiring rdh
+    ///   0.5.0 is compatible with rdh 0.5.3, but not with rdh 0.4.9 or rdh 0.6.0.
+    /// * While a RegistryScope is current on this thread, the library (along with each library it
+    ///   depends upon) is initialized within that scope instead, once per scope, regardless of
+    ///   whether it has been initialized outside of it.
+    ///
+    pub fn try_init(&'static self) -> Result<(), InitError>
+    {
+        if self.is_initialized() == true
+        {
+            return Ok(());
+        }
+        if is_compatible(self.minimum_rdh_version, RDH_VERSION) == false
+        {
+            return Err(InitError::IncompatibleVersion
+                       {
+                           library_name: self.name,
+                           minimum_rdh_version: self.minimum_rdh_version,
+                           rdh_version: RDH_VERSION
+                       });
+        }
+
+        // Track the libraries being initialized on this thread, both to detect dependency cycles
+        // and to report them rather than recursing forever (or deadlocking within Once).
+        let _visit = LibraryVisit::enter(self)?;
+        for parent in self.parents
+        {
+            parent.try_init()?;
+        }
+        match RegistryScope::is_active()
+        {
+            // Scopes are confined to this thread, so there's no need to synchronize.
+            true => self.register_library(),
+            false => self.once.call_once(|| self.register_library())
+        }
+        Ok(())
+    }
+
+    // *** Private functions ***
+
+    //
+    // register_library: Records this library's metadata within the TypeRegistry, then registers
+    //                   its types and downcast handlers.
+    //
+    fn register_library(&self)
+    {
+        TypeRegistry::register_library(LibraryRecord::new(
+          self.identifier(), self.name, self.version, self.minimum_rdh_version,
+          self.parents.iter().map(|parent| parent.identifier()).collect()));
+        (self.register)();
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Public constants
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// RDH_VERSION: The version of the rdh library in use, against which each Library's
+///              minimum_rdh_version is checked.
+///
+pub const RDH_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Public functions
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// init_all: Initializes each of the specified libraries (and the libraries they depend upon), for
+///           applications that consume several sibling libraries.
+///
+/// Parameters:
+/// * libraries (&[&'static Library]): The libraries to initialize.
+///
+/// Panics:
+/// * Under any of the conditions for which Library::init() panics.
+///
+pub fn init_all(libraries: &[&'static Library])
+{
+    for library in libraries
+    {
+        library.init();
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Public macros
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// provision_library: Declares the static Library for an RDH library, whose registration function
+///                    invokes the register_types() function (generated by register_types!) of
+///                    each listed module.
+///
+/// Parameters:
+/// * $attr (meta): Any attributes (including doc comments) to apply to the static.
+/// * $vis (vis): The static's visibility.
+/// * $name (ident): The name to assign to the static.
+/// * $library_name (literal): The library's name.
+/// * $library_uuid (expr): The unique identifier of the library, with which each type registered
+///                         by its modules is associated.
+/// * $version (expr): The library's version, typically env!("CARGO_PKG_VERSION").
+/// * $minimum_rdh_version (expr): The earliest rdh version with which the library is compatible.
+/// * $parent (path): The Library statics of the libraries this library directly depends upon.
+/// * $module (ident): The ::-delimited path (relative to the invoking module) of each module whose
+///                    types are registered by this library, in registration order.
+///
+/// # Example invocations:
+///
+///  For a library that registers types:
+///
+/// ```ignore
+///      provision_library!
+///      {
+///          pub static LIBRARY: "vehicles" (VEHICLES_UUID)
+///            version env!("CARGO_PKG_VERSION"), requires rdh "0.5.0",
+///            extends [rdh::LIBRARY]
+///          {
+///              _hierarchy::vehicle, _hierarchy::car
+///          }
+///      }
+/// ```
+///
+///  For a library that only aggregates others:
+///
+/// ```ignore
+///      provision_library!
+///      {
+///          pub static LIBRARY: "transportation" (TRANSPORTATION_UUID)
+///            version env!("CARGO_PKG_VERSION"), requires rdh "0.5.0",
+///            extends [VEHICLES, VESSELS];
+///      }
+/// ```
+///
+/// Notes:
+/// * Modules are registered in the order listed, so each module should follow those that define
+///   the types it encompasses.
+/// * When a module that invokes register_types! isn't listed here, the compiler reports its UUID
+///   constants as never used.
+///
+#[macro_export]
+macro_rules! provision_library
+{
+    {
+        $( #[$attr: meta] )*
+        $vis: vis static $name: ident: $library_name: literal ($library_uuid: expr)
+          version $version: expr, requires rdh $minimum_rdh_version: expr,
+          extends [$( $parent: path ),* $(,)?]
+        {
+            $( $( $module: ident )::+ ),+ $(,)?
+        }
+    } =>
+    {
+        $( #[$attr] )*
+        $vis static $name: Library = Library::new($library_name, $version, $library_uuid,
+                                                  $minimum_rdh_version, &[$( &$parent ),*],
+                                                  || { $( $( $module )::+::register_types(
+                                                            $library_uuid); )+ });
+    };
+
+    {
+        $( #[$attr: meta] )*
+        $vis: vis static $name: ident: $library_name: literal ($library_uuid: expr)
+          version $version: expr, requires rdh $minimum_rdh_version: expr,
+          extends [$( $parent: path ),* $(,)?];
+    } =>
+    {
+        $( #[$attr] )*
+        $vis static $name: Library = Library::new($library_name, $version, $library_uuid,
+                                                  $minimum_rdh_version, &[$( &$parent ),*],
+                                                  || {});
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Supporting types
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// InitError: Describes why a library couldn't be initialized.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InitError
+{
+    ///
+    /// DependencyCycle: The indicated library was reached again while initializing the libraries
+    ///                  it depends upon.
+    ///
+    DependencyCycle { library_name: &'static str },
+
+    ///
+    /// IncompatibleVersion: The indicated library isn't compatible with the rdh version in use.
+    ///
+    IncompatibleVersion
+    {
+        library_name: &'static str,
+        minimum_rdh_version: &'static str,
+        rdh_version: &'static str
+    }
+}
+impl fmt::Display for InitError
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            InitError::DependencyCycle { library_name } =>
+              write!(formatter, "Indicated library ({}) depends upon itself!", library_name),
+            InitError::IncompatibleVersion { library_name, minimum_rdh_version, rdh_version } =>
+              write!(formatter, "Indicated library ({}) requires rdh {} (or a compatible later \
+                                 version), but rdh {} is in use!", library_name,
+                     minimum_rdh_version, rdh_version)
+        }
+    }
+}
+impl Error for InitError {}
+
+//
+// is_compatible: Determines whether a library requiring the specified minimum rdh version is
+//                compatible with the specified rdh version (see Library::try_init()).
+//
+// Expresses:
+// * If both versions are valid and compatible, true.
+// * Otherwise, false.
+//
+fn is_compatible(minimum_rdh_version: &str, rdh_version: &str) -> bool
+{
+    let (minimum, actual) = match (parse_version(minimum_rdh_version), parse_version(rdh_version))
+    {
+        (Some(minimum), Some(actual)) => (minimum, actual),
+        _ => return false
+    };
+
+    // Versions must share their leftmost nonzero component (or all be zero).
+    let same_series = match minimum
+    {
+        (0, 0, _) => actual.0 == 0 && actual.1 == 0 && actual.2 == minimum.2,
+        (0, minor, _) => actual.0 == 0 && actual.1 == minor,
+        (major, _, _) => actual.0 == major
+    };
+    same_series == true && actual >= minimum
+}
+
+//
+// parse_version: Parses the specified "major[.minor[.patch]]" version, ignoring any pre-release or
+//                build metadata suffix (e.g. "-beta.1" or "+build.5").
+//
+// Expresses:
+// * If successful, the major, minor, and patch components (missing components being 0) within
+//   Some().
+// * Otherwise, None.
+//
+fn parse_version(version: &str) -> Option<(u64, u64, u64)>
+{
+    let core = version.split(['-', '+']).next().unwrap_or_default();
+    let mut components = core.split('.').map(|component| component.parse::<u64>().ok());
+    let major = components.next()??;
+    let minor = components.next().unwrap_or(Some(0))?;
+    let patch = components.next().unwrap_or(Some(0))?;
+    match components.next()
+    {
+        Some(_) => None,
+        None => Some((major, minor, patch))
+    }
+}
+
+//
+// LibraryVisit: Records a library as being initialized on the current thread for as long as it
+//               lives (even if the library's registration function panics).
+//
+struct LibraryVisit;
+impl LibraryVisit
+{
+    //
+    // enter: Records the specified library as being initialized on the current thread.
+    //
+    // Expresses:
+    // * If successful, the LibraryVisit that will remove the record when dropped within Ok().
+    // * If the library is already being initialized on this thread,
+    //   Err(InitError::DependencyCycle).
+    //
+    fn enter(library: &'static Library) -> Result<LibraryVisit, InitError>
+    {
+        INITIALIZING.with(|initializing|
+                          {
+                              let mut initializing = initializing.borrow_mut();
+                              if initializing.iter().any(|visited| ptr::eq(*visited, library))
+                              {
+                                  return Err(InitError::DependencyCycle
+                                             {
+                                                 library_name: library.name
+                                             });
+                              }
+                              initializing.push(library);
+                              Ok(LibraryVisit)
+                          })
+    }
+}
+impl Drop for LibraryVisit
+{
+    fn drop(&mut self)
+    {
+        INITIALIZING.with(|initializing| initializing.borrow_mut().pop());
+    }
+}
+
+thread_local!
+{
+    // The libraries currently being initialized on this thread, outermost first.
+    static INITIALIZING: RefCell<Vec<&'static Library>> = const { RefCell::new(Vec::new()) };
+}
+
+// *** Minutiae ***
+
+use crate::_infrastructure::thaumaturgy::{ LibraryIdentifier, LibraryRecord, RegistryScope,
+                                           TypeRegistry };
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::ptr;
+use std::sync::Once;
@@ -0,0 +1,663 @@
+// ************************************************************************************************
+// Copyright 2023 Gene DeClark and Contributors within this file's version control history
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+// ************************************************************************************************
+
+//!
+//! manifest.rs
+//!
+//! Manifests: Snapshots of the TypeRegistry that may be committed alongside a release, and against
+//!            which later builds may be compared to catch accidental compatibility breaks.
+//!
+//!  Contains:
+//!  * TypeManifest: A snapshot of every registered RDH interface, type, and generic family (its
+//!    name, identifier, parent, and interfaces), which may be exported to and loaded from a text
+//!    file.
+//!  * ManifestEntry: The snapshot of a single interface, type, or generic family.
+//!  * ManifestDrift: A difference between two manifests, e.g. a type whose UUID has changed.
+//!  * ManifestError: Why a manifest couldn't be captured, exported, or loaded.
+//!
+//! THIS IS A WORK IN PROGRESS.
+//!   Although broad strokes will be retained, at this time, everything is subject to change
+//!   without notice.
+//!
+
+//
+// Implementation notes:
+//
+// * Serialized data and plugins rely upon the UUIDs assigned to RDH types, so a UUID changed by
+//   accident (e.g. by a careless merge) is a silent compatibility break. Comparing the live
+//   TypeRegistry with the manifest committed for the previous release surfaces such breaks within
+//   release tests instead.
+// * Manifest files are plain text so that they diff cleanly under version control:
+//   * Lines beginning with "#" are comments (the header lists each registered library).
+//   * Every other line describes one entry as five tab-separated fields: its kind, its identifier,
+//     its parent's identifier (or "-"), its interfaces' comma-separated identifiers (or "-"), and
+//     finally its display name (which may itself contain spaces).
+//   * Entries are ordered by identifier, and each entry's interfaces are too.
+// * Entries are matched between manifests by identifier first, and then (for those left over) by
+//   display name, which is how renamed types are distinguished from re-UUIDed ones.
+//   * Display names are reported by std::any::type_name(), whose output isn't guaranteed to remain
+//     the same across compiler versions, so a renamed type may occasionally be reported after
+//     upgrading the toolchain. Re-exporting the manifest resolves this.
+//
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// TypeManifest struct
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// TypeManifest: A snapshot of every RDH interface, type, and generic family registered within the
+///               TypeRegistry.
+///
+/// Notes:
+/// * Its Display text is the manifest file format, and it implements FromStr to parse that text
+///   back.
+///
+/// # Example
+///
+/// ```
+/// use rdh::_infrastructure::manifest::TypeManifest;
+///
+/// rdh::init();
+/// let manifest = TypeManifest::capture();
+/// let committed: TypeManifest = manifest.to_string().parse().unwrap();
+///
+/// assert!(TypeManifest::capture().drift_from(&committed).is_empty());
+/// ```
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeManifest
+{
+    // The manifest's entries, ordered by type identifier.
+    entries: Vec<ManifestEntry>,
+
+    // The descriptions ("<uuid> <name> <version>") of the libraries registered when the manifest
+    // was captured, for the reader's benefit only (they're never compared).
+    libraries: Vec<String>
+}
+impl TypeManifest
+{
+    // *** Public functions ***
+
+    ///
+    /// capture: Captures a manifest of all RDH interfaces, types, and generic families registered
+    ///          within the TypeRegistry.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_capture() expresses an error.
+    ///
+    pub fn capture() -> TypeManifest
+    {
+        TypeManifest::try_capture().unwrap_or_else(
+          |error| panic!("TypeManifest::capture(): {}", error))
+    }
+
+    ///
+    /// try_capture: Attempts to capture a manifest of all RDH interfaces, types, and generic
+    ///              families registered within the TypeRegistry.
+    ///
+    /// Expresses:
+    /// * If successful, the manifest within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    /// Notes:
+    /// * Should be invoked only once all libraries have been initialized, as registrations made
+    ///   while capturing may or may not be reflected.
+    ///
+    pub fn try_capture() -> Result<TypeManifest, RegistryError>
+    {
+        let mut entries = Vec::new();
+        for type_record in TypeRegistry::try_types()?.into_iter()
+                             .chain(TypeRegistry::try_families()?)
+        {
+            let type_identifier = type_record.type_identifier();
+            let mut interfaces = TypeRegistry::try_interfaces_of(type_identifier)?;
+            interfaces.sort();
+            entries.push(ManifestEntry
+            {
+                type_identifier,
+                type_name: type_record.type_name().to_string(),
+                kind: type_record.kind(),
+                parent: TypeRegistry::try_parent_of(type_identifier)?,
+                interfaces
+            });
+        }
+        entries.sort_by_key(|entry| entry.type_identifier);
+
+        let libraries = TypeRegistry::try_libraries()?
+                          .iter()
+                          .map(|library_record| format!("{} {}",
+                                                        library_record.library_identifier(),
+                                                        library_record))
+                          .collect();
+        Ok(TypeManifest { entries, libraries })
+    }
+
+    ///
+    /// load: Loads the manifest previously exported to the specified file.
+    ///
+    /// Parameters:
+    /// * path (impl AsRef<Path>): The path of the manifest file to load.
+    ///
+    /// Expresses:
+    /// * If successful, the manifest within Ok().
+    /// * If the file couldn't be read, Err(ManifestError::Io).
+    /// * If the file isn't a valid manifest, Err(ManifestError::Malformed).
+    ///
+    pub fn load(path: impl AsRef<Path>) -> Result<TypeManifest, ManifestError>
+    {
+        fs::read_to_string(path).map_err(|error| ManifestError::Io { kind: error.kind() })?
+                                .parse()
+    }
+
+    ///
+    /// export: Exports this manifest to the specified file, replacing any existing file.
+    ///
+    /// Parameters:
+    /// * path (impl AsRef<Path>): The path of the manifest file to write.
+    ///
+    /// Expresses:
+    /// * If successful, Ok(()).
+    /// * If the file couldn't be written, Err(ManifestError::Io).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    ///    // Run once per release, and commit the result:
+    ///    TypeManifest::capture().export("rdh_types.manifest")?;
+    /// ```
+    ///
+    pub fn export(&self, path: impl AsRef<Path>) -> Result<(), ManifestError>
+    {
+        fs::write(path, self.to_string()).map_err(|error| ManifestError::Io { kind: error.kind() })
+    }
+
+    ///
+    /// entries: Retrieves this manifest's entries, ordered by type identifier.
+    ///
+    pub fn entries(&self) -> &[ManifestEntry]
+    {
+        &self.entries
+    }
+
+    ///
+    /// drift_from: Determines how this manifest (e.g. one captured from the live TypeRegistry)
+    ///             differs from the specified baseline manifest (e.g. one committed for the
+    ///             previous release).
+    ///
+    /// Parameters:
+    /// * baseline (&TypeManifest): The manifest against which to compare this one.
+    ///
+    /// Expresses: Each difference found, those concerning the baseline's entries first (in the
+    ///            baseline's order), followed by any added entries. Empty if the manifests match.
+    ///
+    /// Notes:
+    /// * Entries are matched by identifier, so an entry whose display name has changed is reported
+    ///   as ManifestDrift::Renamed.
+    /// * Unmatched entries are then matched by display name, so an entry whose identifier has
+    ///   changed is reported as ManifestDrift::Reidentified.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    ///    // Within release tests:
+    ///    let baseline = TypeManifest::load("rdh_types.manifest")?;
+    ///    let drift = TypeManifest::capture().drift_from(&baseline);
+    ///    assert!(drift.iter().all(|drift| drift.is_breaking() == false), "{:?}", drift);
+    /// ```
+    ///
+    pub fn drift_from(&self, baseline: &TypeManifest) -> Vec<ManifestDrift>
+    {
+        let current_entries: BTreeMap<TypeIdentifier, &ManifestEntry> =
+          self.entries.iter().map(|entry| (entry.type_identifier, entry)).collect();
+        let baseline_identifiers: BTreeSet<TypeIdentifier> =
+          baseline.entries.iter().map(|entry| entry.type_identifier).collect();
+
+        // Current entries whose identifiers are absent from the baseline, indexed by display name.
+        let mut unmatched_entries: HashMap<&str, &ManifestEntry> =
+          self.entries.iter()
+                      .filter(|entry|
+                                baseline_identifiers.contains(&entry.type_identifier) == false)
+                      .map(|entry| (entry.type_name.as_str(), entry))
+                      .collect();
+
+        let mut drift = Vec::new();
+        for baseline_entry in baseline.entries.iter()
+        {
+            let current_entry = match current_entries.get(&baseline_entry.type_identifier)
+            {
+                Some(current_entry) => *current_entry,
+                None =>
+                {
+                    match unmatched_entries.remove(baseline_entry.type_name.as_str())
+                    {
+                        Some(current_entry) => drift.push(ManifestDrift::Reidentified
+                        {
+                            type_name: baseline_entry.type_name.clone(),
+                            old_type_identifier: baseline_entry.type_identifier,
+                            new_type_identifier: current_entry.type_identifier
+                        }),
+                        None => drift.push(ManifestDrift::Removed
+                        {
+                            type_name: baseline_entry.type_name.clone(),
+                            type_identifier: baseline_entry.type_identifier
+                        })
+                    }
+                    continue;
+                }
+            };
+
+            if current_entry.type_name != baseline_entry.type_name
+            {
+                drift.push(ManifestDrift::Renamed
+                {
+                    type_identifier: current_entry.type_identifier,
+                    old_type_name: baseline_entry.type_name.clone(),
+                    new_type_name: current_entry.type_name.clone()
+                });
+            }
+            if current_entry.parent != baseline_entry.parent
+            {
+                drift.push(ManifestDrift::Reparented
+                {
+                    type_name: current_entry.type_name.clone(),
+                    old_parent: baseline_entry.parent,
+                    new_parent: current_entry.parent
+                });
+            }
+            if current_entry.interfaces != baseline_entry.interfaces
+            {
+                drift.push(ManifestDrift::InterfacesChanged
+                {
+                    type_name: current_entry.type_name.clone(),
+                    old_interfaces: baseline_entry.interfaces.clone(),
+                    new_interfaces: current_entry.interfaces.clone()
+                });
+            }
+        }
+
+        // Whatever remains unmatched is new.
+        let mut added_entries: Vec<&ManifestEntry> = unmatched_entries.into_values().collect();
+        added_entries.sort_by_key(|entry| entry.type_identifier);
+        drift.extend(added_entries.into_iter().map(|entry| ManifestDrift::Added
+        {
+            type_name: entry.type_name.clone(),
+            type_identifier: entry.type_identifier
+        }));
+        drift
+    }
+
+    ///
+    /// drift_from_file: Determines how the live TypeRegistry differs from the manifest previously
+    ///                  exported to the specified file (see drift_from()).
+    ///
+    /// Parameters:
+    /// * path (impl AsRef<Path>): The path of the baseline manifest file.
+    ///
+    /// Expresses:
+    /// * If successful, the differences found within Ok().
+    /// * If the baseline manifest couldn't be loaded, the error expressed by load().
+    /// * If the live TypeRegistry couldn't be captured, Err(ManifestError::Registry).
+    ///
+    pub fn drift_from_file(path: impl AsRef<Path>) -> Result<Vec<ManifestDrift>, ManifestError>
+    {
+        let baseline = TypeManifest::load(path)?;
+        let current = TypeManifest::try_capture()
+                        .map_err(|error| ManifestError::Registry { error })?;
+        Ok(current.drift_from(&baseline))
+    }
+}
+impl fmt::Display for TypeManifest
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        writeln!(formatter, "# RDH type manifest")?;
+        writeln!(formatter, "#")?;
+        writeln!(formatter, "# Libraries:")?;
+        for library in self.libraries.iter()
+        {
+            writeln!(formatter, "#   {}", library)?;
+        }
+        writeln!(formatter, "#")?;
+        writeln!(formatter, "# kind\tidentifier\tparent\tinterfaces\tname")?;
+        for entry in self.entries.iter()
+        {
+            writeln!(formatter, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for TypeManifest
+{
+    type Err = ManifestError;
+
+    //
+    // from_str: Parses the Display text of a TypeManifest (i.e. the contents of a manifest file).
+    //
+    fn from_str(text: &str) -> Result<TypeManifest, ManifestError>
+    {
+        let mut entries = Vec::new();
+        let mut libraries = Vec::new();
+        let mut in_libraries = false;
+        for (index, line) in text.lines().enumerate()
+        {
+            let line = line.trim_end_matches('\r');
+            if let Some(comment) = line.strip_prefix('#')
+            {
+                // Retain the library list, purely so that re-exported manifests keep it.
+                match comment.strip_prefix("   ")
+                {
+                    Some(library) if in_libraries == true => libraries.push(library.to_string()),
+                    _ => in_libraries = comment == " Libraries:"
+                }
+            }
+            else if line.is_empty() == false
+            {
+                entries.push(ManifestEntry::parse(line).ok_or(
+                  ManifestError::Malformed { line: index + 1 })?);
+            }
+        }
+        entries.sort_by_key(|entry| entry.type_identifier);
+        Ok(TypeManifest { entries, libraries })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Supporting types
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// ManifestEntry: The snapshot of a single RDH interface, type, or generic family within a
+///                TypeManifest.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManifestEntry
+{
+    // The entry's identifier.
+    type_identifier: TypeIdentifier,
+
+    // The entry's display name.
+    type_name: String,
+
+    // The kind of entry.
+    kind: TypeKind,
+
+    // The identifier of the entry's direct parent concrete type, if any.
+    parent: Option<TypeIdentifier>,
+
+    // The identifiers of the public interfaces the entry implements (or encompasses), ordered by
+    // identifier.
+    interfaces: Vec<TypeIdentifier>
+}
+impl ManifestEntry
+{
+    // *** Public functions ***
+
+    ///
+    /// type_identifier: Retrieves the entry's type identifier.
+    ///
+    pub fn type_identifier(&self) -> TypeIdentifier
+    {
+        self.type_identifier
+    }
+
+    ///
+    /// type_name: Retrieves the entry's display name.
+    ///
+    pub fn type_name(&self) -> &str
+    {
+        &self.type_name
+    }
+
+    ///
+    /// kind: Retrieves the kind of entry (public interface, concrete type, or generic family).
+    ///
+    pub fn kind(&self) -> TypeKind
+    {
+        self.kind
+    }
+
+    ///
+    /// parent: Retrieves the identifier of the entry's direct parent concrete type, if any.
+    ///
+    pub fn parent(&self) -> Option<TypeIdentifier>
+    {
+        self.parent
+    }
+
+    ///
+    /// interfaces: Retrieves the identifiers of the public interfaces the entry implements (or, for
+    ///             public interfaces, encompasses), ordered by identifier.
+    ///
+    pub fn interfaces(&self) -> &[TypeIdentifier]
+    {
+        &self.interfaces
+    }
+
+    // *** Private functions ***
+
+    //
+    // parse: Parses the specified manifest file line (see the implementation notes above).
+    //
+    // Expresses:
+    // * If successful, the entry within Some().
+    // * Otherwise, None.
+    //
+    fn parse(line: &str) -> Option<ManifestEntry>
+    {
+        let mut fields = line.splitn(5, '\t');
+        let kind = match fields.next()?
+        {
+            "interface" => TypeKind::Interface,
+            "concrete" => TypeKind::Concrete,
+            "family" => TypeKind::Family,
+            _ => return None
+        };
+        let type_identifier = fields.next()?.parse().ok()?;
+        let parent = match fields.next()?
+        {
+            "-" => None,
+            parent => Some(parent.parse().ok()?)
+        };
+        let mut interfaces = match fields.next()?
+        {
+            "-" => Vec::new(),
+            interfaces => interfaces.split(',')
+                                    .map(|interface| interface.parse().ok())
+                                    .collect::<Option<Vec<TypeIdentifier>>>()?
+        };
+        interfaces.sort();
+        let type_name = fields.next()?.to_string();
+        Some(ManifestEntry { type_identifier, type_name, kind, parent, interfaces })
+    }
+}
+impl fmt::Display for ManifestEntry
+{
+    //
+    // fmt: Writes the entry as a manifest file line (see the implementation notes above).
+    //
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let kind = match self.kind
+        {
+            TypeKind::Interface => "interface",
+            TypeKind::Concrete => "concrete",
+            TypeKind::Family => "family"
+        };
+        write!(formatter, "{}\t{}\t", kind, self.type_identifier)?;
+        match self.parent
+        {
+            Some(parent) => write!(formatter, "{}\t", parent)?,
+            None => write!(formatter, "-\t")?
+        }
+        if self.interfaces.is_empty() == true
+        {
+            write!(formatter, "-")?;
+        }
+        for (index, interface) in self.interfaces.iter().enumerate()
+        {
+            if index > 0
+            {
+                write!(formatter, ",")?;
+            }
+            write!(formatter, "{}", interface)?;
+        }
+        write!(formatter, "\t{}", self.type_name)
+    }
+}
+
+///
+/// ManifestDrift: Describes a difference between a TypeManifest and its baseline (see
+///                TypeManifest::drift_from()).
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ManifestDrift
+{
+    ///
+    /// Added: The indicated entry is absent from the baseline.
+    ///
+    Added { type_name: String, type_identifier: TypeIdentifier },
+
+    ///
+    /// Removed: The indicated baseline entry is absent.
+    ///
+    Removed { type_name: String, type_identifier: TypeIdentifier },
+
+    ///
+    /// Renamed: The display name of the entry with the indicated identifier has changed.
+    ///
+    Renamed { type_identifier: TypeIdentifier, old_type_name: String, new_type_name: String },
+
+    ///
+    /// Reidentified: The identifier (i.e. the type or library UUID) of the indicated entry has
+    ///               changed.
+    ///
+    Reidentified
+    {
+        type_name: String,
+        old_type_identifier: TypeIdentifier,
+        new_type_identifier: TypeIdentifier
+    },
+
+    ///
+    /// Reparented: The direct parent concrete type of the indicated entry has changed.
+    ///
+    Reparented
+    {
+        type_name: String,
+        old_parent: Option<TypeIdentifier>,
+        new_parent: Option<TypeIdentifier>
+    },
+
+    ///
+    /// InterfacesChanged: The public interfaces the indicated entry implements (or encompasses)
+    ///                    have changed.
+    ///
+    InterfacesChanged
+    {
+        type_name: String,
+        old_interfaces: Vec<TypeIdentifier>,
+        new_interfaces: Vec<TypeIdentifier>
+    }
+}
+impl ManifestDrift
+{
+    // *** Public functions ***
+
+    ///
+    /// is_breaking: Determines whether this difference may break compatibility with serialized
+    ///              data or plugins built against the baseline.
+    ///
+    /// Expresses:
+    /// * For Added and Renamed differences, which leave every baseline identifier intact, false.
+    /// * Otherwise, true.
+    ///
+    pub fn is_breaking(&self) -> bool
+    {
+        matches!(self, ManifestDrift::Added { .. } | ManifestDrift::Renamed { .. }) == false
+    }
+}
+impl fmt::Display for ManifestDrift
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            ManifestDrift::Added { type_name, type_identifier } =>
+              write!(formatter, "Added {} ({})", type_name, type_identifier),
+            ManifestDrift::Removed { type_name, type_identifier } =>
+              write!(formatter, "Removed {} ({})", type_name, type_identifier),
+            ManifestDrift::Renamed { type_identifier, old_type_name, new_type_name } =>
+              write!(formatter, "Renamed {} to {} ({})", old_type_name, new_type_name,
+                     type_identifier),
+            ManifestDrift::Reidentified { type_name, old_type_identifier, new_type_identifier } =>
+              write!(formatter, "Changed the identifier of {} from {} to {}", type_name,
+                     old_type_identifier, new_type_identifier),
+            ManifestDrift::Reparented { type_name, .. } =>
+              write!(formatter, "Changed the parent of {}", type_name),
+            ManifestDrift::InterfacesChanged { type_name, .. } =>
+              write!(formatter, "Changed the interfaces of {}", type_name)
+        }
+    }
+}
+
+///
+/// ManifestError: Describes why a TypeManifest couldn't be captured, exported, or loaded.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ManifestError
+{
+    ///
+    /// Io: The manifest file couldn't be read or written.
+    ///
+    Io { kind: io::ErrorKind },
+
+    ///
+    /// Malformed: The indicated line (numbered from 1) of the manifest text isn't a valid entry.
+    ///
+    Malformed { line: usize },
+
+    ///
+    /// Registry: The live TypeRegistry couldn't be captured.
+    ///
+    Registry { error: RegistryError }
+}
+impl fmt::Display for ManifestError
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            ManifestError::Io { kind } =>
+              write!(formatter, "Unable to read or write the manifest file: {}!", kind),
+            ManifestError::Malformed { line } =>
+              write!(formatter, "Line {} of the manifest isn't a valid entry!", line),
+            ManifestError::Registry { error } =>
+              write!(formatter, "Unable to capture the TypeRegistry: {}", error)
+        }
+    }
+}
+impl Error for ManifestError {}
+
+// *** Minutiae ***
+
+use std::collections::{ BTreeMap, BTreeSet, HashMap };
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use crate::_infrastructure::thaumaturgy::{ RegistryError, TypeIdentifier, TypeKind,
+                                           TypeRegistry };
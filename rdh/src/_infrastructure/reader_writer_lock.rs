@@ -0,0 +1,786 @@
+// ************************************************************************************************
+// Copyright 2023 Gene DeClark and Contributors within this file's version control history
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+// ************************************************************************************************
+
+//!
+//! reader_writer_lock.rs
+//!
+//! ReaderWriterLock: A ReaderWriterLockSlim-like reader/writer lock, ported from our C++ and D
+//!                   implementations (see Work Item #5 in Road Map.md).
+//!
+//!  Contains:
+//!  * ReaderWriterLock: The lock itself, which adds the following to std::sync::RwLock:
+//!    * Timed acquisition: Every lock mode may be requested with a timeout, after which
+//!      LockError::Timeout is expressed rather than waiting until the end of time.
+//!    * Upgradeable read locks: A single thread at a time may hold a read lock that can later be
+//!      upgraded to a write lock without first being released, while other threads continue to
+//!      read.
+//!    * Recursion policy: Whether a thread that already holds the lock may acquire it again, and
+//!      detection (expressed as LockError::Recursion) of recursive acquisitions that would
+//!      otherwise deadlock.
+//!  * ReadLockGuard, UpgradeableReadLockGuard, and WriteLockGuard: The RAII guards through which
+//!    the guarded value is accessed, each releasing its lock when dropped.
+//!  * LockRecursionPolicy and LockError: The lock's recursion policies and acquisition errors.
+//!
+//! THIS IS A WORK IN PROGRESS.
+//!   Although broad strokes will be retained, at this time, everything is subject to change
+//!   without notice.
+//!
+
+//
+// Implementation notes:
+//
+// * Writers are preferred: once a thread is waiting for a write lock (or to upgrade), new read and
+//   upgradeable read requests wait behind it, so a steady stream of readers can't starve writers.
+//   * Recursive read requests from threads that already hold the lock are the exception, as
+//     making them wait on a writer that's itself waiting on them would deadlock.
+// * Unlike ReaderWriterLockSlim, recursive *write* acquisition is never supported, regardless of
+//   recursion policy: two live WriteLockGuards (or a WriteLockGuard and a ReadLockGuard) on the
+//   same thread would hand out aliasing mutable references, which Rust forbids.
+//   * Such requests, and any other request that could only ever deadlock (e.g. a write request
+//     from a thread holding a read lock), express LockError::Recursion immediately.
+//   * Likewise, an upgradeable read lock acquired recursively may only be upgraded once a single
+//     UpgradeableReadLockGuard remains, as the others would otherwise alias the write lock.
+// * Just as with std::sync::RwLock, a thread that panics while holding a write lock poisons the
+//   lock, after which all requests express LockError::Poisoned.
+//
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// ReaderWriterLock struct
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// ReaderWriterLock: A reader/writer lock supporting timed acquisition, upgradeable read locks, and
+///                   a configurable recursion policy.
+///
+/// Generic parameters:
+/// * T: The type of value guarded.
+///
+/// # Example
+///
+/// ```
+/// use rdh::_infrastructure::reader_writer_lock::{ LockError, ReaderWriterLock };
+/// use std::time::Duration;
+///
+/// let lock = ReaderWriterLock::new(1);
+/// {
+///     let mut upgradeable = lock.upgradeable_read().unwrap();
+///     if *upgradeable == 1
+///     {
+///         *upgradeable.upgrade().unwrap() = 2;
+///     }
+/// }
+/// let guard = lock.try_read_for(Duration::from_millis(100)).unwrap();
+/// assert_eq!(*guard, 2);
+/// assert_eq!(lock.try_write_for(Duration::from_millis(100)).err(), Some(LockError::Recursion));
+/// ```
+///
+pub struct ReaderWriterLock<T>
+{
+    // The bookkeeping for the lock's current holders and waiters.
+    state: Mutex<LockState>,
+
+    // Signalled whenever the lock is released (or a waiting writer gives up).
+    released: Condvar,
+
+    // Whether threads that already hold the lock may acquire it again.
+    recursion_policy: LockRecursionPolicy,
+
+    // The guarded value.
+    value: UnsafeCell<T>
+}
+impl<T> ReaderWriterLock<T>
+{
+    // *** Public functions ***
+
+    ///
+    /// new: Creates a new, unlocked instance of the ReaderWriterLock struct that doesn't support
+    ///      recursion.
+    ///
+    /// Parameters:
+    /// * value (T): The value to guard.
+    ///
+    pub const fn new(value: T) -> ReaderWriterLock<T>
+    {
+        ReaderWriterLock::with_recursion_policy(value, LockRecursionPolicy::NoRecursion)
+    }
+
+    ///
+    /// with_recursion_policy: Creates a new, unlocked instance of the ReaderWriterLock struct with
+    ///                        the specified recursion policy.
+    ///
+    /// Parameters:
+    /// * value (T): The value to guard.
+    /// * recursion_policy (LockRecursionPolicy): Whether threads that already hold the lock may
+    ///                                           acquire it again.
+    ///
+    pub const fn with_recursion_policy(value: T, recursion_policy: LockRecursionPolicy) ->
+      ReaderWriterLock<T>
+    {
+        ReaderWriterLock
+        {
+            state: Mutex::new(LockState
+                              {
+                                  readers: Vec::new(),
+                                  upgradeable: None,
+                                  writer: None,
+                                  waiting_writers: 0,
+                                  poisoned: false
+                              }),
+            released: Condvar::new(),
+            recursion_policy,
+            value: UnsafeCell::new(value)
+        }
+    }
+
+    ///
+    /// recursion_policy: Retrieves this lock's recursion policy.
+    ///
+    pub fn recursion_policy(&self) -> LockRecursionPolicy
+    {
+        self.recursion_policy
+    }
+
+    ///
+    /// is_poisoned: Determines whether a thread panicked while holding this lock's write lock.
+    ///
+    pub fn is_poisoned(&self) -> bool
+    {
+        self.lock_state().poisoned
+    }
+
+    ///
+    /// read: Acquires a shared read lock, blocking until it can be acquired.
+    ///
+    /// Expresses:
+    /// * If successful, a guard granting immutable access to the guarded value within Ok().
+    /// * If the current thread holds the write lock, or already holds a read or upgradeable read
+    ///   lock while recursion isn't supported, Err(LockError::Recursion).
+    /// * If the lock has been poisoned, Err(LockError::Poisoned).
+    ///
+    /// Notes:
+    /// * This function will block until shared access can be obtained, potentially forever. Prefer
+    ///   try_read_for() wherever a failure can be handled.
+    ///
+    pub fn read(&self) -> Result<ReadLockGuard<'_, T>, LockError>
+    {
+        self.acquire(LockMode::Read, None)?;
+        Ok(ReadLockGuard { lock: self, thread: thread::current().id(), _not_send: PhantomData })
+    }
+
+    ///
+    /// try_read_for: Acquires a shared read lock, waiting no longer than the specified timeout.
+    ///
+    /// Parameters:
+    /// * timeout (Duration): The longest time to wait for the lock.
+    ///
+    /// Expresses:
+    /// * If successful, a guard granting immutable access to the guarded value within Ok().
+    /// * If the lock couldn't be acquired within the timeout, Err(LockError::Timeout).
+    /// * Otherwise, the same errors as read().
+    ///
+    pub fn try_read_for(&self, timeout: Duration) -> Result<ReadLockGuard<'_, T>, LockError>
+    {
+        self.acquire(LockMode::Read, Some(timeout))?;
+        Ok(ReadLockGuard { lock: self, thread: thread::current().id(), _not_send: PhantomData })
+    }
+
+    ///
+    /// upgradeable_read: Acquires an upgradeable read lock, blocking until it can be acquired.
+    ///
+    /// Expresses:
+    /// * If successful, a guard granting immutable access to the guarded value (which may later be
+    ///   upgraded to grant mutable access) within Ok().
+    /// * If the current thread holds the write lock or a read lock, or already holds the
+    ///   upgradeable read lock while recursion isn't supported, Err(LockError::Recursion).
+    /// * If the lock has been poisoned, Err(LockError::Poisoned).
+    ///
+    /// Notes:
+    /// * Only one thread at a time may hold the upgradeable read lock, though any number of other
+    ///   threads may hold read locks alongside it.
+    /// * This function will block until access can be obtained, potentially forever. Prefer
+    ///   try_upgradeable_read_for() wherever a failure can be handled.
+    ///
+    pub fn upgradeable_read(&self) -> Result<UpgradeableReadLockGuard<'_, T>, LockError>
+    {
+        self.acquire(LockMode::UpgradeableRead, None)?;
+        Ok(UpgradeableReadLockGuard
+           {
+               lock: self,
+               thread: thread::current().id(),
+               _not_send: PhantomData
+           })
+    }
+
+    ///
+    /// try_upgradeable_read_for: Acquires an upgradeable read lock, waiting no longer than the
+    ///                           specified timeout.
+    ///
+    /// Parameters:
+    /// * timeout (Duration): The longest time to wait for the lock.
+    ///
+    /// Expresses:
+    /// * If successful, a guard granting immutable access to the guarded value (which may later be
+    ///   upgraded to grant mutable access) within Ok().
+    /// * If the lock couldn't be acquired within the timeout, Err(LockError::Timeout).
+    /// * Otherwise, the same errors as upgradeable_read().
+    ///
+    pub fn try_upgradeable_read_for(&self, timeout: Duration) ->
+      Result<UpgradeableReadLockGuard<'_, T>, LockError>
+    {
+        self.acquire(LockMode::UpgradeableRead, Some(timeout))?;
+        Ok(UpgradeableReadLockGuard
+           {
+               lock: self,
+               thread: thread::current().id(),
+               _not_send: PhantomData
+           })
+    }
+
+    ///
+    /// write: Acquires the exclusive write lock, blocking until it can be acquired.
+    ///
+    /// Expresses:
+    /// * If successful, a guard granting mutable access to the guarded value within Ok().
+    /// * If the current thread already holds the lock in any mode, Err(LockError::Recursion).
+    ///   (Holders of the upgradeable read lock should upgrade it instead.)
+    /// * If the lock has been poisoned, Err(LockError::Poisoned).
+    ///
+    /// Notes:
+    /// * This function will block until exclusive access can be obtained, potentially forever.
+    ///   Prefer try_write_for() wherever a failure can be handled.
+    ///
+    pub fn write(&self) -> Result<WriteLockGuard<'_, T>, LockError>
+    {
+        self.acquire(LockMode::Write, None)?;
+        Ok(WriteLockGuard { lock: self, _not_send: PhantomData })
+    }
+
+    ///
+    /// try_write_for: Acquires the exclusive write lock, waiting no longer than the specified
+    ///                timeout.
+    ///
+    /// Parameters:
+    /// * timeout (Duration): The longest time to wait for the lock.
+    ///
+    /// Expresses:
+    /// * If successful, a guard granting mutable access to the guarded value within Ok().
+    /// * If the lock couldn't be acquired within the timeout, Err(LockError::Timeout).
+    /// * Otherwise, the same errors as write().
+    ///
+    pub fn try_write_for(&self, timeout: Duration) -> Result<WriteLockGuard<'_, T>, LockError>
+    {
+        self.acquire(LockMode::Write, Some(timeout))?;
+        Ok(WriteLockGuard { lock: self, _not_send: PhantomData })
+    }
+
+    // *** Private functions ***
+
+    //
+    // acquire: Records the current thread as a holder of the lock in the specified mode, first
+    //          waiting (no longer than the specified timeout, if any) until that mode is available.
+    //
+    // Parameters:
+    // * mode (LockMode): The mode in which to acquire the lock.
+    // * timeout (Option<Duration>): The longest time to wait, or None to wait indefinitely.
+    //
+    // Expresses:
+    // * If successful, Ok(()).
+    // * Otherwise, the LockError describing the failure within Err().
+    //
+    fn acquire(&self, mode: LockMode, timeout: Option<Duration>) -> Result<(), LockError>
+    {
+        // A timeout too large to represent is treated as no timeout at all.
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        let thread = thread::current().id();
+        let mut state = self.lock_state();
+        if state.poisoned == true
+        {
+            return Err(LockError::Poisoned);
+        }
+
+        // Reject recursive requests the policy (or Rust's aliasing rules) forbid, and grant
+        // permitted recursive requests immediately.
+        let recursion_supported = self.recursion_policy == LockRecursionPolicy::SupportsRecursion;
+        let holds_read = state.read_count(thread) > 0;
+        let upgradeable_count = match state.upgradeable
+        {
+            Some((holder, count)) if holder == thread => count,
+            _ => 0
+        };
+        let holds_upgradeable = upgradeable_count > 0;
+        if state.writer == Some(thread)
+        {
+            return Err(LockError::Recursion);
+        }
+        match mode
+        {
+            LockMode::Read if holds_read == true || holds_upgradeable == true =>
+            {
+                if recursion_supported == false
+                {
+                    return Err(LockError::Recursion);
+                }
+                state.add_reader(thread);
+                return Ok(());
+            },
+            LockMode::UpgradeableRead if holds_read == true => return Err(LockError::Recursion),
+            LockMode::UpgradeableRead if holds_upgradeable == true =>
+            {
+                if recursion_supported == false
+                {
+                    return Err(LockError::Recursion);
+                }
+                if let Some((_, count)) = state.upgradeable.as_mut()
+                {
+                    *count += 1;
+                }
+                return Ok(());
+            },
+            LockMode::Write if holds_read == true || holds_upgradeable == true =>
+              return Err(LockError::Recursion),
+            LockMode::Upgrade if holds_read == true || upgradeable_count > 1 =>
+              return Err(LockError::Recursion),
+            _ => {}
+        }
+
+        // Wait until the requested mode is available, announcing any pending write so that new
+        // readers queue up behind it.
+        let writing = matches!(mode, LockMode::Write | LockMode::Upgrade);
+        if writing == true
+        {
+            state.waiting_writers += 1;
+        }
+        let mut failure = None;
+        while state.is_available(mode) == false
+        {
+            if state.poisoned == true
+            {
+                failure = Some(LockError::Poisoned);
+                break;
+            }
+            state = match deadline
+            {
+                None => self.released.wait(state).unwrap_or_else(PoisonError::into_inner),
+                Some(deadline) =>
+                {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() == true
+                    {
+                        failure = Some(LockError::Timeout);
+                        break;
+                    }
+                    self.released.wait_timeout(state, remaining)
+                                 .unwrap_or_else(PoisonError::into_inner).0
+                }
+            };
+        }
+        if let Some(error) = failure
+        {
+            // Withdraw the pending write, which may unblock waiting readers.
+            if writing == true
+            {
+                state.waiting_writers -= 1;
+                self.released.notify_all();
+            }
+            return Err(error);
+        }
+
+        // Record the current thread as a holder of the lock.
+        match mode
+        {
+            LockMode::Read => state.add_reader(thread),
+            LockMode::UpgradeableRead => state.upgradeable = Some((thread, 1)),
+            LockMode::Write | LockMode::Upgrade =>
+            {
+                state.waiting_writers -= 1;
+                state.writer = Some(thread);
+            }
+        }
+        Ok(())
+    }
+
+    //
+    // lock_state: Locks this lock's bookkeeping.
+    //
+    // Notes:
+    // * The bookkeeping is never left inconsistent by a panic, so its own poisoning is ignored.
+    //
+    fn lock_state(&self) -> MutexGuard<'_, LockState>
+    {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    //
+    // release: Updates this lock's bookkeeping via the specified function, then wakes all waiting
+    //          threads.
+    //
+    fn release(&self, update: impl FnOnce(&mut LockState))
+    {
+        update(&mut self.lock_state());
+        self.released.notify_all();
+    }
+}
+
+//
+// Send and Sync implementations for ReaderWriterLock (matching those of std::sync::RwLock).
+//
+unsafe impl<T> Send for ReaderWriterLock<T> where T: Send {}
+unsafe impl<T> Sync for ReaderWriterLock<T> where T: Send + Sync {}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Lock guards
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// ReadLockGuard: Grants immutable access to the value guarded by a ReaderWriterLock, releasing its
+///                read lock when dropped.
+///
+pub struct ReadLockGuard<'a, T>
+{
+    // The lock from which this guard was acquired.
+    lock: &'a ReaderWriterLock<T>,
+
+    // The thread holding this guard.
+    thread: ThreadId,
+
+    // Keeps this guard on the thread that acquired it.
+    _not_send: PhantomData<*const ()>
+}
+impl<T> Deref for ReadLockGuard<'_, T>
+{
+    type Target = T;
+
+    fn deref(&self) -> &T
+    {
+        // Safety: No write lock can be held while this read lock is.
+        unsafe { &*self.lock.value.get() }
+    }
+}
+impl<T> Drop for ReadLockGuard<'_, T>
+{
+    fn drop(&mut self)
+    {
+        self.lock.release(|state| state.remove_reader(self.thread));
+    }
+}
+
+///
+/// UpgradeableReadLockGuard: Grants immutable access to the value guarded by a ReaderWriterLock,
+///                           which may be upgraded to mutable access, releasing its upgradeable
+///                           read lock when dropped.
+///
+pub struct UpgradeableReadLockGuard<'a, T>
+{
+    // The lock from which this guard was acquired.
+    lock: &'a ReaderWriterLock<T>,
+
+    // The thread holding this guard.
+    thread: ThreadId,
+
+    // Keeps this guard on the thread that acquired it.
+    _not_send: PhantomData<*const ()>
+}
+impl<T> UpgradeableReadLockGuard<'_, T>
+{
+    // *** Public functions ***
+
+    ///
+    /// upgrade: Upgrades this guard's lock to the exclusive write lock, blocking until all other
+    ///          readers have released the lock.
+    ///
+    /// Expresses:
+    /// * If successful, a guard granting mutable access to the guarded value within Ok(). When it's
+    ///   dropped, this guard reverts to an upgradeable read lock.
+    /// * If the current thread also holds a read lock (or another UpgradeableReadLockGuard, having
+    ///   acquired the upgradeable read lock recursively), Err(LockError::Recursion).
+    /// * If the lock has been poisoned, Err(LockError::Poisoned).
+    ///
+    /// Notes:
+    /// * This function will block until exclusive access can be obtained, potentially forever.
+    ///   Prefer try_upgrade_for() wherever a failure can be handled.
+    ///
+    pub fn upgrade(&mut self) -> Result<WriteLockGuard<'_, T>, LockError>
+    {
+        self.lock.acquire(LockMode::Upgrade, None)?;
+        Ok(WriteLockGuard { lock: self.lock, _not_send: PhantomData })
+    }
+
+    ///
+    /// try_upgrade_for: Upgrades this guard's lock to the exclusive write lock, waiting no longer
+    ///                  than the specified timeout for all other readers to release the lock.
+    ///
+    /// Parameters:
+    /// * timeout (Duration): The longest time to wait for the lock.
+    ///
+    /// Expresses:
+    /// * If successful, a guard granting mutable access to the guarded value within Ok(). When it's
+    ///   dropped, this guard reverts to an upgradeable read lock.
+    /// * If the upgrade couldn't be completed within the timeout, Err(LockError::Timeout).
+    /// * Otherwise, the same errors as upgrade().
+    ///
+    pub fn try_upgrade_for(&mut self, timeout: Duration) -> Result<WriteLockGuard<'_, T>, LockError>
+    {
+        self.lock.acquire(LockMode::Upgrade, Some(timeout))?;
+        Ok(WriteLockGuard { lock: self.lock, _not_send: PhantomData })
+    }
+}
+impl<T> Deref for UpgradeableReadLockGuard<'_, T>
+{
+    type Target = T;
+
+    fn deref(&self) -> &T
+    {
+        // Safety: The only write lock that can be held alongside this one is obtained by upgrading
+        //         it, which mutably borrows this guard for as long as the write lock is held, and
+        //         which is refused while any other UpgradeableReadLockGuard of this thread lives.
+        unsafe { &*self.lock.value.get() }
+    }
+}
+impl<T> Drop for UpgradeableReadLockGuard<'_, T>
+{
+    fn drop(&mut self)
+    {
+        let thread = self.thread;
+        self.lock.release(|state|
+                          {
+                              state.upgradeable = match state.upgradeable
+                              {
+                                  Some((holder, count)) if holder == thread && count > 1 =>
+                                    Some((holder, count - 1)),
+                                  _ => None
+                              };
+                          });
+    }
+}
+
+///
+/// WriteLockGuard: Grants mutable access to the value guarded by a ReaderWriterLock, releasing its
+///                 write lock when dropped.
+///
+/// Notes:
+/// * If the holding thread panics, dropping this guard poisons the lock.
+///
+pub struct WriteLockGuard<'a, T>
+{
+    // The lock from which this guard was acquired.
+    lock: &'a ReaderWriterLock<T>,
+
+    // Keeps this guard on the thread that acquired it.
+    _not_send: PhantomData<*const ()>
+}
+impl<T> Deref for WriteLockGuard<'_, T>
+{
+    type Target = T;
+
+    fn deref(&self) -> &T
+    {
+        // Safety: This thread holds the lock exclusively.
+        unsafe { &*self.lock.value.get() }
+    }
+}
+impl<T> DerefMut for WriteLockGuard<'_, T>
+{
+    fn deref_mut(&mut self) -> &mut T
+    {
+        // Safety: This thread holds the lock exclusively.
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+impl<T> Drop for WriteLockGuard<'_, T>
+{
+    fn drop(&mut self)
+    {
+        let panicking = thread::panicking();
+        self.lock.release(|state|
+                          {
+                              state.writer = None;
+                              state.poisoned |= panicking;
+                          });
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Supporting types
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// LockRecursionPolicy: Specifies whether a thread that already holds a ReaderWriterLock may
+///                      acquire it again.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LockRecursionPolicy
+{
+    ///
+    /// NoRecursion: Any recursive acquisition expresses LockError::Recursion.
+    ///
+    NoRecursion,
+
+    ///
+    /// SupportsRecursion: Threads holding a read or upgradeable read lock may acquire further read
+    ///                    locks, and threads holding the upgradeable read lock may acquire it
+    ///                    again.
+    ///                    * Recursive write acquisition is never supported (see the
+    ///                      implementation notes at the top of this module).
+    ///
+    SupportsRecursion
+}
+
+///
+/// LockError: Describes why a ReaderWriterLock couldn't be acquired.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LockError
+{
+    ///
+    /// Timeout: The lock couldn't be acquired within the specified timeout.
+    ///
+    Timeout,
+
+    ///
+    /// Recursion: The current thread already holds the lock, and acquiring it again in the
+    ///            requested mode is either forbidden by the lock's recursion policy or would
+    ///            deadlock.
+    ///
+    Recursion,
+
+    ///
+    /// Poisoned: A thread panicked while holding the lock's write lock.
+    ///
+    Poisoned
+}
+impl fmt::Display for LockError
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            LockError::Timeout =>
+              write!(formatter, "Timed out waiting to acquire the lock!"),
+            LockError::Recursion =>
+              write!(formatter, "The current thread already holds the lock, and may not acquire \
+                                 it again in the requested mode!"),
+            LockError::Poisoned =>
+              write!(formatter, "The lock was poisoned by a thread that panicked while holding \
+                                 it!")
+        }
+    }
+}
+impl Error for LockError {}
+
+//
+// LockMode: The modes in which a ReaderWriterLock may be acquired.
+//
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum LockMode
+{
+    // A shared read lock.
+    Read,
+
+    // The (single) upgradeable read lock.
+    UpgradeableRead,
+
+    // The exclusive write lock.
+    Write,
+
+    // The exclusive write lock, obtained by the holder of the upgradeable read lock.
+    Upgrade
+}
+
+//
+// LockState: The bookkeeping for a ReaderWriterLock's current holders and waiters.
+//
+struct LockState
+{
+    // Each thread holding read locks, along with the number it holds.
+    readers: Vec<(ThreadId, usize)>,
+
+    // The thread holding the upgradeable read lock, along with the number of times it holds it.
+    upgradeable: Option<(ThreadId, usize)>,
+
+    // The thread holding the write lock.
+    writer: Option<ThreadId>,
+
+    // The number of threads waiting for the write lock (including to upgrade).
+    waiting_writers: usize,
+
+    // Whether a thread panicked while holding the write lock.
+    poisoned: bool
+}
+impl LockState
+{
+    //
+    // is_available: Determines whether a thread that doesn't already hold the lock may now acquire
+    //               it in the specified mode.
+    //
+    fn is_available(&self, mode: LockMode) -> bool
+    {
+        match mode
+        {
+            LockMode::Read => self.writer.is_none() && self.waiting_writers == 0,
+            LockMode::UpgradeableRead => self.writer.is_none() && self.upgradeable.is_none() &&
+                                         self.waiting_writers == 0,
+            LockMode::Write => self.writer.is_none() && self.upgradeable.is_none() &&
+                               self.readers.is_empty(),
+
+            // The upgrading thread's own upgradeable read lock excludes all other writers.
+            LockMode::Upgrade => self.readers.is_empty()
+        }
+    }
+
+    //
+    // read_count: Retrieves the number of read locks held by the specified thread.
+    //
+    fn read_count(&self, thread: ThreadId) -> usize
+    {
+        self.readers.iter().find(|(reader, _)| *reader == thread).map_or(0, |(_, count)| *count)
+    }
+
+    //
+    // add_reader: Records one more read lock as held by the specified thread.
+    //
+    fn add_reader(&mut self, thread: ThreadId)
+    {
+        match self.readers.iter_mut().find(|(reader, _)| *reader == thread)
+        {
+            Some((_, count)) => *count += 1,
+            None => self.readers.push((thread, 1))
+        }
+    }
+
+    //
+    // remove_reader: Records one fewer read lock as held by the specified thread.
+    //
+    fn remove_reader(&mut self, thread: ThreadId)
+    {
+        if let Some(index) = self.readers.iter().position(|(reader, _)| *reader == thread)
+        {
+            self.readers[index].1 -= 1;
+            if self.readers[index].1 == 0
+            {
+                self.readers.swap_remove(index);
+            }
+        }
+    }
+}
+
+// *** Minutiae ***
+
+use std::cell::UnsafeCell;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{ Deref, DerefMut };
+use std::sync::{ Condvar, Mutex, MutexGuard, PoisonError };
+use std::thread::{ self, ThreadId };
+use std::time::{ Duration, Instant };
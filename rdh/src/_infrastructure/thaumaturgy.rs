@@ -36,7 +36,8 @@
 //!                (Construct) instances may be requested.
 //!  * Transmutation: Macros that create traits, structs, and impls to enable casting between
 //!                   (IConstructs) implemented by the same (Construct), even when the type of that
-//!                   (Construct) is unknown.
+//!                   (Construct) is unknown, along with the struct through which such casts may be
+//!                   applied to owned (Box, Rc, and Arc) pointers.
 //! 
 //! THIS IS A WORK IN PROGRESS.
 //!   Although broad strokes will be retained, at this time, everything is subject to change
@@ -56,27 +57,522 @@
 //     * This inherently requires maintaining and updating static data.
 //   * To ensure memory safety, Rust requires thread safety for all static mutable data access
 //     events.
-//     * In the interests of performance, we use std::sync::rwlock rather than a simple mutex.
+//     * Each singleton instance (and the lock that guards it) is held within a LazyLock or
+//       OnceLock static and created upon first use, so no static mut (or unsafe code) is involved.
+//       Each singleton must therefore be Send and Sync, as must the downcasting handlers they own.
+//     * In the interests of performance, we use a reader/writer lock rather than a simple mutex.
 //       * This allows us to lock down access only when a data pool must be updated (i.e. only
 //         during initialization), and to otherwise support any number of simulataneous read
 //         events.
-//     * Unfortunately, we're presented with only two options out of the box for handling
-//       contested access requests: wait until the end of time, or don't wait *at all*.
+//     * std::sync::RwLock presents only two options for handling contested access requests: wait
+//       until the end of time, or don't wait *at all*.
 //       * Waiting forever is obviously not ideal, but expecting to never have to wait is
 //         obviously unrealistic.
 //         * (I mean, if we could expect to not wait at all, we wouldn't have to worry about
 //           thread safety... right?)
-//     * This puts the burden on us to implement bespoke code to handle retries, surface
-//       reasonable errors to callers, and so on.
-//     * While we already have a bespoke reader/writer lock implementation that handles all of
-//       this, porting it to Rust from its current C++ and D versions would be a non-trivial
-//       undertaking.
-//     * Until this porting work is done, our implementation is kept purposely simple:
-//       * Threads block indefinitely while waiting for contested locks.
+//     * We therefore use ReaderWriterLock (see reader_writer_lock.rs), our port of our C++ and D
+//       ReaderWriterLockSlim-like implementation, which supports timed acquisition:
+//       * Registrations wait no longer than FreezableLock::DEFAULT_TIMEOUT for contested locks,
+//         and then express RegistryError::Timeout (or panic, for the non-try_* functions).
+//       * Lookups and downcasts, which only ever contend with brief registrations, wait until
+//         access is granted. Recursive reads by the same thread are supported.
 //       * The application crashes if we're ever unable to obtain access to a singleton instance
-//         for any reason.
+//         for any reason, except when registering or looking up types and downcast handlers via
+//         the try_* functions, which express a RegistryError instead.
+//   * Once all libraries have been initialized, the data pools never change again, so
+//     TypeRegistry::freeze() moves each of them out of its lock (see FreezableLock), after which
+//     they're read without any locking at all and registrations are rejected.
+//   * Each singleton may be replaced on a single thread by the corresponding data pool of a
+//     RegistryScope, so that tests may register (and drop) throwaway types in isolation.
+//     * Each singleton's rwlock() function consults the current scope (a thread-local lookup)
+//       before falling back to the process-wide instance.
 //
 
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Abjuration: The power to protect.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+//
+// *** Public structs ***
+//
+
+///
+/// FreezableLock: The reader/writer lock that guards each of this module's singleton data pools
+///                until the TypeRegistry is frozen, after which the guarded value may be read
+///                without acquiring any lock.
+///
+/// Generic parameters:
+/// * T: The type of value guarded.
+///
+/// Notes:
+/// * Freezing moves the guarded value out of the lock and into an immutable cell. Freezing the
+///   TypeRegistry freezes every downcasting struct along with it, and any FreezableLock left
+///   unfrozen by then freezes itself the first time it's read without contention afterward.
+/// * Until frozen, the value is guarded by a ReaderWriterLock that supports recursive reads.
+///   Writes (i.e. registrations) wait no longer than the lock's timeout, after which
+///   RegistryError::Timeout is expressed rather than hanging.
+/// * This struct is public only because macro-generated singletons use it; consumers of the type
+///   hierarchy should never need to.
+///
+pub struct FreezableLock<T>
+{
+    // The name of the registry the value represents, reported within any RegistryError.
+    registry_name: &'static str,
+
+    // The longest time to wait for the write lock.
+    timeout: Duration,
+
+    // The lock that guards the value until it's frozen (after which it holds None).
+    rwlock: ReaderWriterLock<Option<T>>,
+
+    // The frozen value, once frozen.
+    frozen: OnceLock<T>
+}
+impl<T> FreezableLock<T>
+{
+    ///
+    /// DEFAULT_TIMEOUT: The longest time registrations wait for a registry's write lock, unless
+    ///                  otherwise specified via with_timeout().
+    ///
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    // *** Public functions ***
+
+    ///
+    /// new: Creates a new, unfrozen instance of the FreezableLock struct guarding the specified
+    ///      value, whose writes wait no longer than DEFAULT_TIMEOUT.
+    ///
+    /// Parameters:
+    /// * registry_name (&'static str): The name of the registry the value represents, reported
+    ///                                 within any RegistryError.
+    /// * value (T): The value to guard.
+    ///
+    pub const fn new(registry_name: &'static str, value: T) -> FreezableLock<T>
+    {
+        FreezableLock::with_timeout(registry_name, value, FreezableLock::<T>::DEFAULT_TIMEOUT)
+    }
+
+    ///
+    /// with_timeout: Creates a new, unfrozen instance of the FreezableLock struct guarding the
+    ///               specified value, whose writes wait no longer than the specified timeout.
+    ///
+    /// Parameters:
+    /// * registry_name (&'static str): The name of the registry the value represents, reported
+    ///                                 within any RegistryError.
+    /// * value (T): The value to guard.
+    /// * timeout (Duration): The longest time to wait for the write lock.
+    ///
+    pub const fn with_timeout(registry_name: &'static str, value: T, timeout: Duration) ->
+      FreezableLock<T>
+    {
+        FreezableLock
+        {
+            registry_name,
+            timeout,
+            rwlock: ReaderWriterLock::with_recursion_policy(Some(value),
+                                                            LockRecursionPolicy::SupportsRecursion),
+            frozen: OnceLock::new()
+        }
+    }
+
+    ///
+    /// read: Obtains immutable access to the guarded value.
+    ///
+    /// Expresses:
+    /// * If frozen, the frozen value (without acquiring any lock) within Ok().
+    /// * Otherwise, if successful, the value guarded by a read lock within Ok().
+    /// * If the current thread holds the write lock, Err(RegistryError::LockRecursion).
+    /// * If the lock has been poisoned, Err(RegistryError::PoisonedLock).
+    ///
+    /// Notes:
+    /// * Until frozen, this function will block until shared access can be obtained. As writes
+    ///   never hold the lock for long (and time out rather than waiting forever), such waits are
+    ///   brief.
+    /// * If the TypeRegistry has been frozen but this value hasn't, the value is frozen first,
+    ///   but only if that's possible without waiting; otherwise, it's read under the lock.
+    ///
+    pub fn read(&self) -> Result<FreezableReadGuard<'_, T>, RegistryError>
+    {
+        if let Some(value) = self.frozen_value()
+        {
+            return Ok(FreezableReadGuard::Frozen(value));
+        }
+        let guard = self.rwlock.read().map_err(|error| self.registry_error(error))?;
+        match guard.is_some()
+        {
+            true => Ok(FreezableReadGuard::Locked(guard)),
+
+            // Frozen by another thread in the meantime....
+            false => self.frozen.get().map(FreezableReadGuard::Frozen)
+                                      .ok_or(RegistryError::PoisonedLock)
+        }
+    }
+
+    ///
+    /// write: Obtains mutable access to the guarded value, so long as it hasn't been frozen.
+    ///
+    /// Expresses:
+    /// * If successful, the value guarded by a write lock within Ok().
+    /// * If the value (or the TypeRegistry) has been frozen, Err(RegistryError::Frozen).
+    /// * If the write lock couldn't be acquired within this lock's timeout,
+    ///   Err(RegistryError::Timeout).
+    /// * If the current thread already holds the lock, Err(RegistryError::LockRecursion).
+    /// * If the lock has been poisoned, Err(RegistryError::PoisonedLock).
+    ///
+    pub fn write(&self) -> Result<FreezableWriteGuard<'_, T>, RegistryError>
+    {
+        let guard = self.rwlock.try_write_for(self.timeout)
+                               .map_err(|error| self.registry_error(error))?;
+        if guard.is_none() == true || TypeRegistry::is_frozen() == true
+        {
+            return Err(RegistryError::Frozen { registry_name: self.registry_name });
+        }
+        Ok(FreezableWriteGuard { guard })
+    }
+
+    ///
+    /// freeze: Moves the guarded value into immutable storage, after which it may be read without
+    ///         acquiring any lock, but never written.
+    ///
+    /// Expresses:
+    /// * If successful (or if already frozen), Ok(()).
+    /// * If the write lock couldn't be acquired within this lock's timeout,
+    ///   Err(RegistryError::Timeout).
+    /// * If the current thread already holds the lock, Err(RegistryError::LockRecursion).
+    /// * If the lock has been poisoned, Err(RegistryError::PoisonedLock).
+    ///
+    pub fn freeze(&self) -> Result<(), RegistryError>
+    {
+        self.freeze_within(self.timeout)
+    }
+
+    ///
+    /// is_frozen: Determines whether the guarded value has been frozen.
+    ///
+    /// Expresses:
+    /// * If frozen, true.
+    /// * Otherwise, false (which, once the TypeRegistry has been frozen, is only the case until
+    ///   this value is next read without contention).
+    ///
+    pub fn is_frozen(&self) -> bool
+    {
+        self.frozen.get().is_some()
+    }
+
+    // *** Private functions ***
+
+    //
+    // freeze_within: Moves the guarded value into immutable storage, waiting no longer than the
+    //                specified timeout for the write lock.
+    //
+    // Expresses:
+    // * If successful (or if already frozen), Ok(()).
+    // * Otherwise, the same errors as freeze().
+    //
+    fn freeze_within(&self, timeout: Duration) -> Result<(), RegistryError>
+    {
+        let mut guard = self.rwlock.try_write_for(timeout)
+                                   .map_err(|error| self.registry_error(error))?;
+        if let Some(value) = guard.take()
+        {
+            // Only ever set here, while the value is still held by the lock, so never already set.
+            let _ = self.frozen.set(value);
+        }
+        Ok(())
+    }
+
+    //
+    // frozen_value: Retrieves the frozen value, first freezing it if the TypeRegistry has been
+    //               frozen in the meantime and the write lock is uncontested.
+    //
+    // Expresses:
+    // * If frozen, the frozen value within Some().
+    // * If not frozen, None.
+    //
+    // Notes:
+    // * Never waits for the write lock: should it be held (including by the current thread), the
+    //   value continues to be read under the lock until it can be frozen.
+    //
+    fn frozen_value(&self) -> Option<&T>
+    {
+        if self.is_frozen() == false && TypeRegistry::is_frozen() == true
+        {
+            let _ = self.freeze_within(Duration::ZERO);
+        }
+        self.frozen.get()
+    }
+
+    //
+    // registry_error: Translates the specified LockError into the corresponding RegistryError.
+    //
+    fn registry_error(&self, error: LockError) -> RegistryError
+    {
+        match error
+        {
+            LockError::Timeout => RegistryError::Timeout { registry_name: self.registry_name },
+            LockError::Recursion =>
+              RegistryError::LockRecursion { registry_name: self.registry_name },
+            LockError::Poisoned => RegistryError::PoisonedLock
+        }
+    }
+}
+
+///
+/// FreezableReadGuard: Grants immutable access to the value guarded by a FreezableLock.
+///
+pub enum FreezableReadGuard<'a, T>
+{
+    ///
+    /// Frozen: The value has been frozen and is accessed without any lock.
+    ///
+    Frozen(&'a T),
+
+    ///
+    /// Locked: The value is accessed under the read lock held by this guard.
+    ///
+    Locked(ReadLockGuard<'a, Option<T>>)
+}
+impl<T> Deref for FreezableReadGuard<'_, T>
+{
+    type Target = T;
+
+    fn deref(&self) -> &T
+    {
+        match self
+        {
+            FreezableReadGuard::Frozen(value) => value,
+            FreezableReadGuard::Locked(guard) => guard.as_ref().expect(
+              "FreezableReadGuard::deref(): The guarded value is unexpectedly missing!")
+        }
+    }
+}
+
+///
+/// FreezableWriteGuard: Grants mutable access to the (unfrozen) value guarded by a FreezableLock.
+///
+pub struct FreezableWriteGuard<'a, T>
+{
+    // The write lock held by this guard.
+    guard: WriteLockGuard<'a, Option<T>>
+}
+impl<T> Deref for FreezableWriteGuard<'_, T>
+{
+    type Target = T;
+
+    fn deref(&self) -> &T
+    {
+        self.guard.as_ref().expect(
+          "FreezableWriteGuard::deref(): The guarded value is unexpectedly missing!")
+    }
+}
+impl<T> DerefMut for FreezableWriteGuard<'_, T>
+{
+    fn deref_mut(&mut self) -> &mut T
+    {
+        self.guard.as_mut().expect(
+          "FreezableWriteGuard::deref_mut(): The guarded value is unexpectedly missing!")
+    }
+}
+
+///
+/// RegistryLock: A handle to the lock that guards a registry: either that of the process-wide
+///               singleton instance, or that of the RegistryScope current on this thread.
+///
+/// Notes:
+/// * Dereferences to the FreezableLock it refers to.
+/// * A scoped lock (and the registry it guards) is kept alive for as long as any handle to it is
+///   held, even should its RegistryScope be dropped in the meantime.
+/// * This enum is public only because macro-generated singletons use it; consumers of the type
+///   hierarchy should never need to.
+///
+pub enum RegistryLock<T> where T: 'static
+{
+    /// The lock that guards the process-wide singleton instance.
+    Global(&'static FreezableLock<T>),
+
+    /// The lock that guards the instance of a RegistryScope.
+    Scoped(Rc<FreezableLock<T>>)
+}
+impl<T> Deref for RegistryLock<T>
+{
+    type Target = FreezableLock<T>;
+
+    fn deref(&self) -> &FreezableLock<T>
+    {
+        match self
+        {
+            RegistryLock::Global(rwlock) => rwlock,
+            RegistryLock::Scoped(rwlock) => rwlock
+        }
+    }
+}
+
+///
+/// RegistryScope: An isolated set of registries that, for as long as it lives, replaces the
+///                process-wide TypeRegistry and downcasting struct singletons on the current
+///                thread.
+///
+/// Notes:
+/// * Intended for tests: each test may enter its own scope, initialize only the libraries (or
+///   register only the throwaway types) it needs, and drop the scope once done, without colliding
+///   with other tests or with the application's registrations.
+///   * Within a scope, nothing is registered until registered within that scope. Each Library
+///     must therefore be initialized again within it, which registers the library's types anew
+///     (see Library::try_init()), and unregistered libraries may be tested for as such.
+///   * A scope's TypeRegistry may be frozen independently of the process-wide TypeRegistry.
+/// * Scopes are strictly per-thread: threads spawned within a scope see the process-wide
+///   registries (or their own scopes). RegistryScope is therefore neither Send nor Sync.
+/// * Scopes may be nested, in which case the most recently entered scope is current.
+/// * Per-type identity caches (see Lineage::identity_cache()) are bypassed while a scope is
+///   current, so type queries consult the scope's TypeRegistry each time.
+///
+/// # Example
+///
+/// ```
+/// use rdh::_infrastructure::thaumaturgy::{ RegistryScope, TypeRegistry };
+///
+/// rdh::init();
+/// {
+///     let _scope = RegistryScope::enter();
+///     assert!(TypeRegistry::types().is_empty());
+///
+///     rdh::init();
+///     assert_eq!(TypeRegistry::types().len(), 2);
+/// }
+/// assert_eq!(TypeRegistry::libraries().len(), 1);
+/// ```
+///
+pub struct RegistryScope
+{
+    // The unique (per thread) number identifying this scope within SCOPES.
+    scope_number: u64,
+
+    // Keeps this struct from being sent to (or shared with) other threads.
+    thread_bound: PhantomData<*const ()>
+}
+impl RegistryScope
+{
+    // *** Public functions ***
+
+    ///
+    /// enter: Creates a new, empty set of registries and makes it current on this thread until the
+    ///        expressed RegistryScope is dropped.
+    ///
+    pub fn enter() -> RegistryScope
+    {
+        LIVE_SCOPES.fetch_add(1, Ordering::Relaxed);
+        SCOPES.with(|scopes|
+                    {
+                        let mut scopes = scopes.borrow_mut();
+                        let scope_number = scopes.last().map_or(0, |scope| scope.0 + 1);
+                        scopes.push((scope_number, HashMap::new()));
+                        RegistryScope { scope_number, thread_bound: PhantomData }
+                    })
+    }
+
+    ///
+    /// is_active: Determines whether a RegistryScope is current on this thread.
+    ///
+    /// Expresses:
+    /// * If so, true.
+    /// * Otherwise (i.e. if the process-wide registries are in use), false.
+    ///
+    pub fn is_active() -> bool
+    {
+        // A scope entered on this thread is always counted (in program order), so the thread-local
+        // lookup is needed only while some thread has a scope.
+        if LIVE_SCOPES.load(Ordering::Relaxed) == 0
+        {
+            return false;
+        }
+        SCOPES.with(|scopes| scopes.borrow().is_empty() == false)
+    }
+
+    ///
+    /// current_lock: Retrieves the lock that guards the indicated registry within the RegistryScope
+    ///               current on this thread (creating both when necessary).
+    ///
+    /// Generic parameters:
+    /// * T ('static): The type of registry (e.g. TypeRegistry or a downcasting struct).
+    ///
+    /// Parameters:
+    /// * create (impl FnOnce() -> FreezableLock<T>): Creates the lock and the empty registry it
+    ///                                               guards, should the current scope lack one.
+    ///
+    /// Expresses:
+    /// * If a RegistryScope is current, the lock within Some().
+    /// * Otherwise, None.
+    ///
+    /// Notes:
+    /// * This function is public only because macro-generated singletons use it; consumers of the
+    ///   type hierarchy should never need to.
+    ///
+    pub fn current_lock<T>(create: impl FnOnce() -> FreezableLock<T>) -> Option<RegistryLock<T>>
+        where T: 'static
+    {
+        if LIVE_SCOPES.load(Ordering::Relaxed) == 0
+        {
+            return None;
+        }
+
+        // Create any missing registry outside the borrow of SCOPES, as creating a registry may
+        // itself consult the scope's other registries.
+        let type_id = TypeId::of::<T>();
+        let registry = SCOPES.with(|scopes|
+                                   {
+                                       scopes.borrow().last()
+                                             .map(|(_, registries)| registries.get(&type_id)
+                                                                              .cloned())
+                                   })?;
+        let registry = match registry
+        {
+            Some(registry) => registry,
+            None =>
+            {
+                let created: Rc<dyn Any> = Rc::new(create());
+                SCOPES.with(|scopes|
+                            {
+                                let mut scopes = scopes.borrow_mut();
+                                let (_, registries) = scopes.last_mut()?;
+                                Some(Rc::clone(registries.entry(type_id).or_insert(created)))
+                            })?
+            }
+        };
+        registry.downcast::<FreezableLock<T>>().ok().map(RegistryLock::Scoped)
+    }
+}
+impl Drop for RegistryScope
+{
+    fn drop(&mut self)
+    {
+        // Release the borrow of SCOPES before dropping the registries, whose downcast handlers may
+        // themselves consult the registries as they're dropped.
+        let _registries = SCOPES.with(|scopes|
+                                      {
+                                          let mut scopes = scopes.borrow_mut();
+                                          scopes.iter()
+                                                .position(|scope| scope.0 == self.scope_number)
+                                                .map(|index| scopes.remove(index))
+                                      });
+        LIVE_SCOPES.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+// The registries of a single RegistryScope, indexed by the TypeIds of the registry types they
+// replace.
+type ScopeRegistries = HashMap<TypeId, Rc<dyn Any>>;
+
+// The number of RegistryScopes alive on all threads, which lets the common case (no scope at all)
+// skip the thread-local lookup.
+static LIVE_SCOPES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local!
+{
+    // The registries of the RegistryScopes entered on this thread (each numbered), current scope
+    // last.
+    static SCOPES: RefCell<Vec<(u64, ScopeRegistries)>> = const { RefCell::new(Vec::new()) };
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Divination: The power to know the unknowable.
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -89,6 +585,10 @@
 /// Divinator: The interface through which an RDH type's identifier and the identifier of the
 ///            library in which it was defined can be retrieved.
 ///
+/// Notes:
+/// * Each (non-generic) type's identifiers are resolved within the TypeRegistry only once, and
+///   cached thereafter (see TypeRegistry::cached_type_identifier_of()).
+///
 pub trait Divinator
 {
     ///
@@ -102,15 +602,15 @@ pub trait Divinator
     ///
     fn library_identifier(&self) -> LibraryIdentifier;
 }
-impl<T> Divinator for T where T: IConstruct + ?Sized
+impl<T> Divinator for T where T: IConstruct + Lineage + ?Sized + 'static
 {
     fn type_identifier(&self) -> TypeIdentifier
     {
-        TypeRegistry::type_identifier_of::<T>()
+        TypeRegistry::cached_type_identifier_of::<T>()
     }
     fn library_identifier(&self) -> LibraryIdentifier
     {
-        TypeRegistry::library_identifier_of::<T>()
+        TypeRegistry::cached_type_identifier_of::<T>().library_identifier
     }
 }
 
@@ -118,6 +618,11 @@ impl<T> Divinator for T where T: IConstruct + ?Sized
 /// ConcreteDivinator: The trait that exposes information about (underlying) concrete types and the
 ///                    interfaces they implement.
 ///
+/// Notes:
+/// * implements(), is(), and is_a() express false (rather than panicking) when the types they
+///   consult can't be resolved within the TypeRegistry, e.g. within a RegistryScope in which the
+///   library defining them hasn't been initialized.
+///
 pub trait ConcreteDivinator
 {
     ///
@@ -126,12 +631,31 @@ pub trait ConcreteDivinator
     ///
     fn concrete_type_identifier(&self) -> TypeIdentifier;
 
+    ///
+    /// try_concrete_type_identifier: Attempts to retrieve the identifier for the current
+    ///                               instance's concrete type.
+    ///
+    /// Expresses:
+    /// * If successful, the concrete type's identifier within Ok().
+    /// * Otherwise, any error expressed by TypeRegistry::try_cached_type_identifier_of().
+    ///
+    fn try_concrete_type_identifier(&self) -> Result<TypeIdentifier, RegistryError>;
+
     ///
     /// concrete_library_identifier: Retrieves the identifier for the library in which the current
     ///                              instance's concrete type is defined.
     ///
     fn concrete_library_identifier(&self) -> LibraryIdentifier;
 
+    ///
+    /// concrete_type_id: Retrieves the Rust TypeId of the current instance's concrete type.
+    ///
+    /// Notes:
+    /// * Unlike concrete_type_identifier(), this function never consults the TypeRegistry, and so
+    ///   never acquires its lock. It's used to key the downcasting dispatch tables.
+    ///
+    fn concrete_type_id(&self) -> TypeId;
+
     ///
     /// implements: Determines whether this object's concrete type implements the indicated public
     ///             interface.
@@ -140,7 +664,8 @@ pub trait ConcreteDivinator
     /// * interface_type (TypeIdentifier): The type identifier of the interface type to look for.
     ///
     /// Expresses:
-    /// * If this object's concrete type implements the indicated public interface, expresses true.
+    /// * If this object's concrete type implements the indicated public interface, or a member of
+    ///   the indicated generic interface family, expresses true.
     /// * Otherwise, expresses false.
     ///
     fn implements(&self, interface_type: TypeIdentifier) -> bool;
@@ -152,19 +677,22 @@ pub trait ConcreteDivinator
     /// * test_type (TypeIdentifier): The type identifier of the type being tested.
     ///
     /// Expresses:
-    /// * If this object's concrete type *literally* is the indicated type, expresses true.
+    /// * If this object's concrete type *literally* is the indicated type, or is a member of the
+    ///   indicated generic type family, expresses true.
     /// * Otherwise, expresses false.
     ///
     /// IMPORTANT DISTINCTIONS FROM INHERITANCE-BASED LANGUAGES:
     /// * Although in languages such as C#, "is" will also return true if test_type is an ancestor
     ///   of this object's type or one of its implemented interfaces, this function will return
     ///   *false*.
-    ///   * The *only* time "is" will return true is when the specified TypeIdentifier is the
-    ///     *exact* type identifier for this object's concrete type.
+    ///   * The *only* times "is" will return true are when the specified TypeIdentifier is the
+    ///     *exact* type identifier for this object's concrete type, or the identifier of the
+    ///     generic family to which it belongs (e.g. Property<bool> "is" a PropertyFamily).
     /// * To determine whether a type implements a given interface, call implements() rather than
     ///   is().
     /// * To determine whether a type is "derived" from another type (or, really, *encompasses*
-    ///   it), call implements(), specifying the type's corresponding public interface, e.g.:
+    ///   it), call is_a(), or call implements() specifying the type's corresponding public
+    ///   interface, e.g.:
     ///
     /// ```
     ///    if car.implements(TypeRegistry::type_identifier_of::<IVehicle>())
@@ -184,310 +712,3300 @@ pub trait ConcreteDivinator
     ///     *conceptually*-- Car and Vehicle are different objects at different memory addresses!
     ///
     fn is(&self, test_type: TypeIdentifier) -> bool;
-}
-
-//
-// *** Public structs ***
-//
-
-///
-/// TypeRegistry: The singleton casting support struct used to track information about RDH types.
-///
-pub struct TypeRegistry
-{
-    // NOTE: Code outside this module remains unable to instantiate this struct only while at least
-    //       one field is kept private and no public constructor or Default implementation is
-    //       added.
-
-    // The table of type identifiers, indexed by their names.
-    type_map: HashMap<String, TypeIdentifier>
-}
-impl TypeRegistry
-{
-    // *** Public functions ***
 
     ///
-    /// register_type: Registers the indicated RDH interface or type with the specified type UUID
-    ///                and library UUID.
-    ///
-    /// Generic parameters:
-    /// * T (IConstruct + ?Sized): The RDH type to register.
+    /// is_a: Determines whether this object's concrete type is the indicated concrete type or
+    ///       encompasses it, directly or indirectly (i.e. "inherits" from it).
     ///
     /// Parameters:
-    /// * type_uuid (u128): The unique identifier to assign to the type being registered.
-    /// * library_uuid (u128): The unique identifier of the library in which the type is defined.
+    /// * test_type (TypeIdentifier): The type identifier of the concrete type (or generic family)
+    ///                               being tested.
     ///
-    /// Panics:
-    /// * If unable to acquire the lock that guards the TypeRegistry singleton instance.
-    /// * If unable to acquire the mutable TypeRegistry singleton instance.
-    /// * If the indicated type has already been registred.
-    /// * If the specified type UUID has already been registered to a different type.
+    /// Expresses:
+    /// * If is() would express true, or if the indicated type (or a member of the indicated
+    ///   generic family) is an ancestor of this object's concrete type, expresses true.
+    /// * Otherwise, expresses false.
     ///
     /// Notes:
-    /// * This function will block until exclusive singleton instance access can be obtained,
-    ///   potentially forever.
-    ///   * See this module's Implementation Notes.
+    /// * This is the closest RDH analog to C#'s "is" operator for concrete types, e.g.:
     ///
-    pub fn register_type<T>(type_uuid: u128,
-                            library_uuid: u128)
-        where T: IConstruct + ?Sized
+    /// ```
+    ///    if iconstruct.is_a(TypeRegistry::type_identifier_of::<UIElement>())
+    ///    {
+    ///        println!("It's some kind of UIElement!");
+    ///    }
+    /// ```
+    ///
+    /// * Ancestry is taken from the lineage recorded when each type was registered.
+    ///   * See TypeRegistry::ancestors_of().
+    /// * Public interfaces are never ancestors; to test for them, call implements() instead.
+    /// * A true result does *not* mean this object may be cast to the indicated concrete type.
+    ///   * as_concrete() still recovers only the exact concrete type (see is()); to recover the
+    ///     instance of an ancestor type, call as_ancestor() instead.
+    ///
+    fn is_a(&self, test_type: TypeIdentifier) -> bool;
+}
+
+///
+/// Lineage: The trait that exposes, without requiring an instance, the direct parent and
+///          implemented public interfaces of an RDH interface or type.
+///
+/// # Safety
+///
+/// * Necromancer follows the parent accessors recorded for each concrete type to reach its
+///   ancestors, then reinterprets the instance reached as the ancestor type indicated by the
+///   recorded parent type ids, so implementors must uphold the following:
+///   * parent_type_id() and parent_accessors() must both express None, or must respectively
+///     express TypeId::of::<P>() and ParentAccessors::of::<Self, P>() for the same parent type P.
+/// * Implementing this trait by hand is therefore never necessary (nor advisable).
+///
+/// Notes:
+/// * Implemented for each concrete type and the public interface it introduces by the
+///   provision_transmutation! macro.
+/// * Consulted by the TypeRegistry when the type is registered, allowing lineage queries such as
+///   TypeRegistry::parent_of() and TypeRegistry::types_implementing() to be answered.
+///
+pub unsafe trait Lineage
+{
+    ///
+    /// KIND: The kind of RDH type implementing this trait (i.e. TypeKind::Concrete for concrete
+    ///       types, or TypeKind::Interface for public interfaces).
+    ///
+    const KIND: TypeKind;
+
+    ///
+    /// parent_type_id: Retrieves the Rust type id of this concrete type's direct parent concrete
+    ///                 type.
+    ///
+    /// Expresses:
+    /// * For concrete types other than the hierarchy root, the parent type's id within Some().
+    /// * For the hierarchy root and for public interfaces, None.
+    ///
+    /// Notes:
+    /// * Must agree with parent_accessors() (see this trait's Safety section).
+    ///
+    fn parent_type_id() -> Option<TypeId>;
+
+    ///
+    /// parent_accessors: Retrieves the accessors through which this concrete type's encompassed
+    ///                   direct parent instance may be reached.
+    ///
+    /// Expresses:
+    /// * For concrete types other than the hierarchy root, the parent accessors within Some().
+    /// * For the hierarchy root and for public interfaces, None.
+    ///
+    /// Notes:
+    /// * Must agree with parent_type_id() (see this trait's Safety section).
+    ///
+    fn parent_accessors() -> Option<ParentAccessors>;
+
+    ///
+    /// interface_type_ids: Retrieves the Rust type ids of the public interfaces implemented by this
+    ///                     concrete type, or encompassed by this public interface.
+    ///
+    /// Expresses:
+    /// * For concrete types, the ids of the introduced interface followed by all upstream
+    ///   interfaces.
+    /// * For public interfaces, the ids of all upstream interfaces (excluding the interface
+    ///   itself).
+    ///
+    fn interface_type_ids() -> Vec<TypeId>;
+
+    ///
+    /// identity_cache: Retrieves the per-type static within which this type's TypeIdentifier and
+    ///                 the identifier of its generic family (if any) are cached once resolved.
+    ///
+    /// Expresses:
+    /// * For non-generic interfaces and types, the type's cache within Some().
+    /// * Otherwise, None.
+    ///
+    /// Notes:
+    /// * Rust statics can't be generic, so instantiations of generic types (e.g. Property<bool>)
+    ///   have no cache, and their identifiers are looked up within the TypeRegistry each time.
+    ///   This is also the default implementation's behavior.
+    /// * See TypeRegistry::cached_type_identifier_of() and TypeRegistry::family_of().
+    ///
+    fn identity_cache() -> Option<&'static OnceLock<(TypeIdentifier, Option<TypeIdentifier>)>>
+    {
+        None
+    }
+}
+
+///
+/// Registered: The marker trait implemented by the register_types! macro for each interface, type,
+///             and downcast handler struct it registers.
+///
+/// Notes:
+/// * provision_transmutation! and extend_downcasting! require it of each (non-generic) type,
+///   interface, and downcast handler struct they provision, so that one can't be defined without
+///   being listed within its module's register_types! invocation.
+///
+#[diagnostic::on_unimplemented(
+  message = "`{Self}` is not listed within its module's register_types! invocation",
+  label = "not registered",
+  note = "list it within the types [...] or handlers [...] of the module's register_types!")]
+pub trait Registered
+{
+    ///
+    /// REGISTERED: Referenced by provision_transmutation! and extend_downcasting! to require this
+    ///             trait at compile time.
+    ///
+    const REGISTERED: () = ();
+}
+
+///
+/// Downcastable: The trait that associates each public interface's trait object type (e.g.
+///               dyn ICar) with the global downcasting struct that coordinates downcasts to it.
+///
+/// Notes:
+/// * Implemented for each downcastable public interface by the provision_transmutation! macro.
+///
+pub trait Downcastable
+{
+    ///
+    /// Downcaster: The global downcasting struct that coordinates downcasts to the interface.
+    ///
+    type Downcaster;
+}
+
+///
+/// DerivedCasts: The trait through which a concrete type's (IConstruct) objects are made
+///               downcastable to the public interfaces introduced by its ancestors.
+///
+/// Notes:
+/// * Implemented for each interface and type by the provision_transmutation! macro, and invoked
+///   by register_types! for each interface and type it registers, so a derived type can't be
+///   left unregistered with its ancestors' downcasting structs.
+///
+pub trait DerivedCasts
+{
+    ///
+    /// register_derived_casts: Registers this concrete type with the global downcasting struct of
+    ///                         each public interface introduced by its ancestors (other than
+    ///                         IConstruct), unless already registered there.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_register_derived_type() expresses an error,
+    ///   other than RegistryError::DuplicateDerivedType (e.g. for types already within a
+    ///   registered handler's include [...] list).
+    ///
+    /// Notes:
+    /// * Does nothing for public interfaces and the hierarchy root (the default implementation).
+    ///
+    fn register_derived_casts()
+    {
+    }
+}
+
+//
+// *** Public structs ***
+//
+
+///
+/// TypeRegistry: The singleton casting support struct used to track information about RDH types.
+///
+/// Notes:
+/// * Registered types are keyed by their std::any::TypeId, rather than by their names.
+///   * Type names are retained only as display metadata (e.g. within panic messages), so the form
+///     taken by std::any::type_name() values no longer affects type identification.
+///   * Because TypeIds are only available for types with 'static lifetimes, all RDH interfaces and
+///     types must be 'static. (RDH types hold no borrowed data, so in practice this is always the
+///     case.)
+/// * Generic RDH types are registered per instantiation, e.g. Property<bool> and Property<String>
+///   are registered separately and receive distinct type identifiers.
+///   * Instantiations may additionally be registered as members of a generic family, which allows
+///     ConcreteDivinator::is() and implements() to answer questions about *any* instantiation.
+///   * See register_family() and register_family_member().
+///
+pub struct TypeRegistry
+{
+    // NOTE: Code outside this module remains unable to instantiate this struct only while at least
+    //       one field is kept private and no public constructor or Default implementation is
+    //       added.
+
+    // The table of registered type records, indexed by their Rust type ids.
+    type_map: HashMap<TypeId, TypeRecord>,
+
+    // The table of registered generic family records, indexed by the Rust type ids of the marker
+    // types that represent them.
+    family_map: HashMap<TypeId, TypeRecord>,
+
+    // The table of Rust type ids (of types and family markers alike), indexed by the type UUIDs
+    // assigned to them.
+    uuid_map: HashMap<u128, TypeId>,
+
+    // The table of parent accessors, indexed by the Rust type ids of the concrete types that
+    // encompass those parents.
+    accessor_map: HashMap<TypeId, ParentAccessors>,
+
+    // The table of registered library records, indexed by the identifiers of their libraries.
+    library_map: HashMap<LibraryIdentifier, LibraryRecord>,
+
+    // The functions that freeze each downcasting struct created so far (see try_freeze()).
+    freezers: Vec<fn() -> Result<(), RegistryError>>
+}
+impl TypeRegistry
+{
+    // *** Public functions ***
+
+    ///
+    /// register_type: Registers the indicated RDH interface or type with the specified type UUID
+    ///                and library UUID.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + Lineage + ?Sized + 'static): The RDH type to register.
+    ///
+    /// Parameters:
+    /// * type_uuid (u128): The unique identifier to assign to the type being registered.
+    /// * library_uuid (u128): The unique identifier of the library in which the type is defined.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_register_type() expresses an error.
+    ///
+    /// Notes:
+    /// * This function is a thin wrapper around try_register_type().
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn register_type<T>(type_uuid: u128,
+                            library_uuid: u128)
+        where T: IConstruct + Lineage + ?Sized + 'static
+    {
+        if let Err(error) = TypeRegistry::try_register_type::<T>(type_uuid, library_uuid)
+        {
+            panic!("TypeRegistry::register_type<T>(): {}", error);
+        }
+    }
+
+    ///
+    /// try_register_type: Attempts to register the indicated RDH interface or type with the
+    ///                    specified type UUID and library UUID.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + Lineage + ?Sized + 'static): The RDH type to register.
+    ///
+    /// Parameters:
+    /// * type_uuid (u128): The unique identifier to assign to the type being registered.
+    /// * library_uuid (u128): The unique identifier of the library in which the type is defined.
+    ///
+    /// Expresses:
+    /// * If successful, Ok(()).
+    /// * If the indicated type has already been registered, Err(RegistryError::DuplicateType).
+    /// * If the specified type UUID has already been registered to a different type,
+    ///   Err(RegistryError::DuplicateUuid).
+    /// * If the TypeRegistry has been frozen, Err(RegistryError::Frozen).
+    /// * If exclusive singleton instance access couldn't be obtained in time,
+    ///   Err(RegistryError::Timeout).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    /// Notes:
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn try_register_type<T>(type_uuid: u128,
+                                library_uuid: u128) -> Result<(), RegistryError>
+        where T: IConstruct + Lineage + ?Sized + 'static
+    {
+        // Implementation note: It would be better to allow access only to developers extending,
+        //                      rather than consuming, the type hierarchy, but Rust has no way to
+        //                      model that across libraries.
+
+        let rwlock = TypeRegistry::rwlock();
+        let mut instance = rwlock.write()?;
+        instance.insert_type_record::<T>(TypeIdentifier
+                                         {
+                                             type_uuid,
+                                             library_identifier: LibraryIdentifier
+                                             {
+                                                 uuid: library_uuid
+                                             }
+                                         },
+                                         None)
+    }
+
+    ///
+    /// register_family: Registers a generic RDH type family, represented by the indicated marker
+    ///                  type, with the specified family UUID and library UUID.
+    ///
+    /// Generic parameters:
+    /// * F (?Sized + 'static): The marker type that represents the family, e.g. PropertyFamily
+    ///                         for all instantiations of Property<T>.
+    ///
+    /// Parameters:
+    /// * family_uuid (u128): The unique identifier to assign to the family being registered.
+    /// * library_uuid (u128): The unique identifier of the library in which the family's generic
+    ///                        type is defined.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_register_family() expresses an error.
+    ///
+    /// Notes:
+    /// * Family identifiers are TypeIdentifiers and share a UUID space with all RDH types.
+    /// * Any 'static type may serve as a family marker, but a dedicated unit struct is recommended.
+    /// * This function is a thin wrapper around try_register_family().
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn register_family<F>(family_uuid: u128,
+                              library_uuid: u128)
+        where F: ?Sized + 'static
+    {
+        if let Err(error) = TypeRegistry::try_register_family::<F>(family_uuid, library_uuid)
+        {
+            panic!("TypeRegistry::register_family<F>(): {}", error);
+        }
+    }
+
+    ///
+    /// try_register_family: Attempts to register a generic RDH type family, represented by the
+    ///                      indicated marker type, with the specified family UUID and library
+    ///                      UUID.
+    ///
+    /// Generic parameters:
+    /// * F (?Sized + 'static): The marker type that represents the family.
+    ///
+    /// Parameters:
+    /// * family_uuid (u128): The unique identifier to assign to the family being registered.
+    /// * library_uuid (u128): The unique identifier of the library in which the family's generic
+    ///                        type is defined.
+    ///
+    /// Expresses:
+    /// * If successful, Ok(()).
+    /// * If the indicated family has already been registered, Err(RegistryError::DuplicateType).
+    /// * If the specified family UUID has already been registered to a different type or family,
+    ///   Err(RegistryError::DuplicateUuid).
+    /// * If the TypeRegistry has been frozen, Err(RegistryError::Frozen).
+    /// * If exclusive singleton instance access couldn't be obtained in time,
+    ///   Err(RegistryError::Timeout).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    /// Notes:
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn try_register_family<F>(family_uuid: u128,
+                                  library_uuid: u128) -> Result<(), RegistryError>
+        where F: ?Sized + 'static
+    {
+        // Implementation note: It would be better to allow access only to developers extending,
+        //                      rather than consuming, the type hierarchy, but Rust has no way to
+        //                      model that across libraries.
+
+        let family_id = TypeId::of::<F>();
+        let family_name = any::type_name::<F>();
+        let rwlock = TypeRegistry::rwlock();
+        let mut instance = rwlock.write()?;
+
+        // Make sure the family's marker hasn't already been registered....
+        if instance.family_map.contains_key(&family_id) == true ||
+           instance.type_map.contains_key(&family_id) == true
+        {
+            return Err(RegistryError::DuplicateType { type_name: family_name });
+        }
+
+        // Also make sure the family's UUID hasn't already been registered....
+        if instance.uuid_map.contains_key(&family_uuid) == true
+        {
+            return Err(RegistryError::DuplicateUuid { uuid: family_uuid, type_name: family_name });
+        }
+
+        instance.uuid_map.insert(family_uuid, family_id);
+        instance.family_map.insert(family_id, TypeRecord
+                                              {
+                                                  type_identifier: TypeIdentifier
+                                                  {
+                                                      type_uuid: family_uuid,
+                                                      library_identifier: LibraryIdentifier
+                                                      {
+                                                          uuid: library_uuid
+                                                      }
+                                                  },
+                                                  type_name: family_name,
+                                                  kind: TypeKind::Family,
+                                                  family: None,
+                                                  parent: None,
+                                                  interfaces: Vec::new()
+                                              });
+        Ok(())
+    }
+
+    ///
+    /// register_family_member: Registers the indicated instantiation of a generic RDH interface or
+    ///                         type with the specified type UUID as a member of the indicated
+    ///                         generic family.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + Lineage + ?Sized + 'static): The generic RDH type instantiation to
+    ///                                                register, e.g. Property<bool>.
+    /// * F (?Sized + 'static): The marker type that represents the instantiation's family.
+    ///
+    /// Parameters:
+    /// * type_uuid (u128): The unique identifier to assign to the instantiation being registered.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_register_family_member() expresses an error.
+    ///
+    /// Notes:
+    /// * The instantiation is recorded as belonging to the library in which its family is defined.
+    /// * This function is a thin wrapper around try_register_family_member().
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn register_family_member<T, F>(type_uuid: u128)
+        where T: IConstruct + Lineage + ?Sized + 'static,
+              F: ?Sized + 'static
+    {
+        if let Err(error) = TypeRegistry::try_register_family_member::<T, F>(type_uuid)
+        {
+            panic!("TypeRegistry::register_family_member<T, F>(): {}", error);
+        }
+    }
+
+    ///
+    /// try_register_family_member: Attempts to register the indicated instantiation of a generic
+    ///                             RDH interface or type with the specified type UUID as a member
+    ///                             of the indicated generic family.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + Lineage + ?Sized + 'static): The generic RDH type instantiation to
+    ///                                                register.
+    /// * F (?Sized + 'static): The marker type that represents the instantiation's family.
+    ///
+    /// Parameters:
+    /// * type_uuid (u128): The unique identifier to assign to the instantiation being registered.
+    ///
+    /// Expresses:
+    /// * If successful, Ok(()).
+    /// * If the indicated family has not been registered, Err(RegistryError::UnregisteredType).
+    /// * If the indicated type has already been registered, Err(RegistryError::DuplicateType).
+    /// * If the specified type UUID has already been registered to a different type,
+    ///   Err(RegistryError::DuplicateUuid).
+    /// * If the TypeRegistry has been frozen, Err(RegistryError::Frozen).
+    /// * If exclusive singleton instance access couldn't be obtained in time,
+    ///   Err(RegistryError::Timeout).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    /// Notes:
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn try_register_family_member<T, F>(type_uuid: u128) -> Result<(), RegistryError>
+        where T: IConstruct + Lineage + ?Sized + 'static,
+              F: ?Sized + 'static
+    {
+        // Implementation note: It would be better to allow access only to developers extending,
+        //                      rather than consuming, the type hierarchy, but Rust has no way to
+        //                      model that across libraries.
+
+        let rwlock = TypeRegistry::rwlock();
+        let mut instance = rwlock.write()?;
+        let family_identifier = match instance.family_map.get(&TypeId::of::<F>())
+        {
+            Some(family_record) => family_record.type_identifier,
+            None => return Err(RegistryError::UnregisteredType
+                               {
+                                   type_name: any::type_name::<F>()
+                               })
+        };
+        instance.insert_type_record::<T>(TypeIdentifier
+                                         {
+                                             type_uuid,
+                                             library_identifier:
+                                               family_identifier.library_identifier
+                                         },
+                                         Some(family_identifier))
+    }
+
+    ///
+    /// register_library: Registers the specified RDH library metadata.
+    ///
+    /// Parameters:
+    /// * library_record (LibraryRecord): The metadata of the library being registered.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_register_library() expresses an error.
+    ///
+    /// Notes:
+    /// * Invoked by Library::try_init() as each library is initialized, prior to registering the
+    ///   library's types.
+    /// * This function is a thin wrapper around try_register_library().
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn register_library(library_record: LibraryRecord)
+    {
+        if let Err(error) = TypeRegistry::try_register_library(library_record)
+        {
+            panic!("TypeRegistry::register_library(): {}", error);
+        }
+    }
+
+    ///
+    /// try_register_library: Attempts to register the specified RDH library metadata.
+    ///
+    /// Parameters:
+    /// * library_record (LibraryRecord): The metadata of the library being registered.
+    ///
+    /// Expresses:
+    /// * If successful, Ok(()).
+    /// * If a library with the same identifier has already been registered,
+    ///   Err(RegistryError::DuplicateLibrary).
+    /// * If the TypeRegistry has been frozen, Err(RegistryError::Frozen).
+    /// * If exclusive singleton instance access couldn't be obtained in time,
+    ///   Err(RegistryError::Timeout).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    /// Notes:
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn try_register_library(library_record: LibraryRecord) -> Result<(), RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let mut instance = rwlock.write()?;
+        if instance.library_map.contains_key(&library_record.library_identifier) == true
+        {
+            return Err(RegistryError::DuplicateLibrary
+                       {
+                           uuid: library_record.library_identifier.uuid,
+                           library_name: library_record.name
+                       });
+        }
+        instance.library_map.insert(library_record.library_identifier, library_record);
+        Ok(())
+    }
+
+    ///
+    /// freeze: Freezes the TypeRegistry and all downcasting structs, after which they may be read
+    ///         without acquiring any lock, but reject further registrations.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_freeze() expresses an error.
+    ///
+    /// Notes:
+    /// * This function is a thin wrapper around try_freeze().
+    /// * Intended to be invoked by the project's main() function once the init() function for the
+    ///   top-level RDH library has returned.
+    ///
+    pub fn freeze()
+    {
+        if let Err(error) = TypeRegistry::try_freeze()
+        {
+            panic!("TypeRegistry::freeze(): {}", error);
+        }
+    }
+
+    ///
+    /// try_freeze: Attempts to freeze the TypeRegistry and all downcasting structs, after which
+    ///             they may be read without acquiring any lock, but reject further registrations.
+    ///
+    /// Expresses:
+    /// * If successful (or if the TypeRegistry is already frozen), Ok(()).
+    /// * If exclusive singleton instance access couldn't be obtained in time,
+    ///   Err(RegistryError::Timeout).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    /// Notes:
+    /// * Once frozen, all try_register_* functions (of TypeRegistry and of each downcasting
+    ///   struct) express Err(RegistryError::Frozen).
+    /// * Each downcasting struct is frozen along with the TypeRegistry. Any that can't be (e.g.
+    ///   due to a timeout) freezes itself the first time it's read without contention afterward.
+    /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for exclusive
+    ///   singleton instance access.
+    ///   * See this module's Implementation Notes.
+    ///
+    pub fn try_freeze() -> Result<(), RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let freezers = rwlock.read()?.freezers.clone();
+        rwlock.freeze()?;
+        freezers.iter().try_for_each(|freeze| freeze())
+    }
+
+    ///
+    /// try_enlist_freezer: Attempts to enlist the specified function, which freezes a downcasting
+    ///                     struct, to be invoked when the TypeRegistry is frozen.
+    ///
+    /// Parameters:
+    /// * freeze (fn() -> Result<(), RegistryError>): The function that freezes the downcasting
+    ///                                               struct.
+    ///
+    /// Expresses:
+    /// * If successful, Ok(()).
+    /// * Otherwise, the same errors as try_register_library() (other than DuplicateLibrary).
+    ///
+    /// Notes:
+    /// * This function is public only because macro-generated downcasting structs use it;
+    ///   consumers of the type hierarchy should never need to.
+    ///
+    pub fn try_enlist_freezer(freeze: fn() -> Result<(), RegistryError>) ->
+      Result<(), RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        rwlock.write()?.freezers.push(freeze);
+        Ok(())
+    }
+
+    ///
+    /// is_frozen: Determines whether the TypeRegistry (and with it, each downcasting struct) has
+    ///            been frozen.
+    ///
+    /// Expresses:
+    /// * If freeze() or try_freeze() has succeeded, true.
+    /// * Otherwise, false.
+    ///
+    pub fn is_frozen() -> bool
+    {
+        TypeRegistry::rwlock().is_frozen()
+    }
+
+    ///
+    /// type_identifier_of: Retrieves the type identifier assigned to the indicated RDH type.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized + 'static): The RDH type whose type identifier to retrieve.
+    ///
+    /// Expresses: The type's identifier.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_type_identifier_of() expresses an error.
+    ///
+    pub fn type_identifier_of<T>() -> TypeIdentifier
+        where T: IConstruct + ?Sized + 'static
+    {
+        TypeRegistry::try_type_identifier_of::<T>().unwrap_or_else(
+          |error| panic!("TypeRegistry::type_identifier_of<T>(): {}", error))
+    }
+
+    ///
+    /// try_type_identifier_of: Attempts to retrieve the type identifier assigned to the indicated
+    ///                         RDH type.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized + 'static): The RDH type whose type identifier to retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the type's identifier within Ok().
+    /// * If the indicated type has not been registered, Err(RegistryError::UnregisteredType).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_type_identifier_of<T>() -> Result<TypeIdentifier, RegistryError>
+        where T: IConstruct + ?Sized + 'static
+    {
+        TypeRegistry::try_type_record_of::<T, _>(|type_record| type_record.type_identifier)
+    }
+
+    ///
+    /// cached_type_identifier_of: Retrieves the type identifier assigned to the indicated RDH
+    ///                            type, consulting the TypeRegistry only the first time it's
+    ///                            requested.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + Lineage + ?Sized + 'static): The RDH type whose type identifier to
+    ///                                                retrieve.
+    ///
+    /// Expresses: The type's identifier.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_cached_type_identifier_of() expresses an error.
+    ///
+    /// Notes:
+    /// * Used by Divinator::type_identifier() and ConcreteDivinator::is() and implements(), so
+    ///   that runtime type queries reduce to a few comparisons.
+    ///
+    pub fn cached_type_identifier_of<T>() -> TypeIdentifier
+        where T: IConstruct + Lineage + ?Sized + 'static
+    {
+        TypeRegistry::try_cached_type_identifier_of::<T>().unwrap_or_else(
+          |error| panic!("TypeRegistry::cached_type_identifier_of<T>(): {}", error))
+    }
+
+    ///
+    /// try_cached_type_identifier_of: Attempts to retrieve the type identifier assigned to the
+    ///                                indicated RDH type, consulting the TypeRegistry only the
+    ///                                first time it's requested.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + Lineage + ?Sized + 'static): The RDH type whose type identifier to
+    ///                                                retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the type's identifier within Ok().
+    /// * Otherwise, any error expressed by try_type_identifier_of().
+    ///
+    /// Notes:
+    /// * Once resolved, the identifier is cached within the static expressed by
+    ///   Lineage::identity_cache() (when one exists). Failures are never cached.
+    ///
+    pub fn try_cached_type_identifier_of<T>() -> Result<TypeIdentifier, RegistryError>
+        where T: IConstruct + Lineage + ?Sized + 'static
+    {
+        TypeRegistry::try_identity_of::<T>().map(|(type_identifier, _)| type_identifier)
+    }
+
+    ///
+    /// family_of: Retrieves the identifier of the generic family of which the indicated RDH type
+    ///            is a member, consulting the TypeRegistry only the first time it's requested.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + Lineage + ?Sized + 'static): The RDH type whose family to retrieve.
+    ///
+    /// Expresses:
+    /// * If the type is a member of a generic family, the family's identifier within Some().
+    /// * Otherwise, None.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_family_of() expresses an error.
+    ///
+    pub fn family_of<T>() -> Option<TypeIdentifier>
+        where T: IConstruct + Lineage + ?Sized + 'static
+    {
+        TypeRegistry::try_family_of::<T>().unwrap_or_else(
+          |error| panic!("TypeRegistry::family_of<T>(): {}", error))
+    }
+
+    ///
+    /// try_family_of: Attempts to retrieve the identifier of the generic family of which the
+    ///                indicated RDH type is a member, consulting the TypeRegistry only the first
+    ///                time it's requested.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + Lineage + ?Sized + 'static): The RDH type whose family to retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the family's identifier within Some() (or None if the type is not a
+    ///   family member) within Ok().
+    /// * If the indicated type has not been registered, Err(RegistryError::UnregisteredType).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_family_of<T>() -> Result<Option<TypeIdentifier>, RegistryError>
+        where T: IConstruct + Lineage + ?Sized + 'static
+    {
+        TypeRegistry::try_identity_of::<T>().map(|(_, family)| family)
+    }
+
+    ///
+    /// library_identifier_of: Retrieves the identifier assigned to the library in which the
+    ///                        indicated RDH type is defined.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized + 'static): The RDH type whose library identifier to retrieve.
+    ///
+    /// Expresses: The library's identifier.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_library_identifier_of() expresses an error.
+    ///
+    pub fn library_identifier_of<T>() -> LibraryIdentifier
+        where T: IConstruct + ?Sized + 'static
+    {
+        TypeRegistry::try_library_identifier_of::<T>().unwrap_or_else(
+          |error| panic!("TypeRegistry::library_identifier_of<T>(): {}", error))
+    }
+
+    ///
+    /// try_library_identifier_of: Attempts to retrieve the identifier assigned to the library in
+    ///                            which the indicated RDH type is defined.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized + 'static): The RDH type whose library identifier to retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the library's identifier within Ok().
+    /// * If the indicated type has not been registered, Err(RegistryError::UnregisteredType).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_library_identifier_of<T>() -> Result<LibraryIdentifier, RegistryError>
+        where T: IConstruct + ?Sized + 'static
+    {
+        TypeRegistry::try_type_record_of::<T, _>(
+          |type_record| type_record.type_identifier.library_identifier)
+    }
+
+    ///
+    /// type_name_of: Retrieves the display name recorded for the indicated RDH type when it was
+    ///               registered.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized + 'static): The RDH type whose display name to retrieve.
+    ///
+    /// Expresses: The type's display name, e.g. "rdh::_hierarchy::construct::Construct".
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_type_name_of() expresses an error.
+    ///
+    /// Notes:
+    /// * Display names are taken from std::any::type_name(), whose output Rust does not guarantee
+    ///   to remain stable. They're suitable for diagnostics, but must never be used to identify
+    ///   types. Use TypeIdentifiers for that instead.
+    ///
+    pub fn type_name_of<T>() -> &'static str
+        where T: IConstruct + ?Sized + 'static
+    {
+        TypeRegistry::try_type_name_of::<T>().unwrap_or_else(
+          |error| panic!("TypeRegistry::type_name_of<T>(): {}", error))
+    }
+
+    ///
+    /// try_type_name_of: Attempts to retrieve the display name recorded for the indicated RDH type
+    ///                   when it was registered.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized + 'static): The RDH type whose display name to retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the type's display name within Ok().
+    /// * If the indicated type has not been registered, Err(RegistryError::UnregisteredType).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_type_name_of<T>() -> Result<&'static str, RegistryError>
+        where T: IConstruct + ?Sized + 'static
+    {
+        TypeRegistry::try_type_record_of::<T, _>(|type_record| type_record.type_name)
+    }
+
+    ///
+    /// family_identifier_of: Retrieves the type identifier assigned to the generic family
+    ///                       represented by the indicated marker type.
+    ///
+    /// Generic parameters:
+    /// * F (?Sized + 'static): The marker type that represents the family.
+    ///
+    /// Expresses: The family's identifier.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_family_identifier_of() expresses an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///    if iconstruct.is(TypeRegistry::family_identifier_of::<PropertyFamily>())
+    ///    {
+    ///        println!("It's a Property of some kind!");
+    ///    }
+    /// ```
+    ///
+    pub fn family_identifier_of<F>() -> TypeIdentifier
+        where F: ?Sized + 'static
+    {
+        TypeRegistry::try_family_identifier_of::<F>().unwrap_or_else(
+          |error| panic!("TypeRegistry::family_identifier_of<F>(): {}", error))
+    }
+
+    ///
+    /// try_family_identifier_of: Attempts to retrieve the type identifier assigned to the generic
+    ///                           family represented by the indicated marker type.
+    ///
+    /// Generic parameters:
+    /// * F (?Sized + 'static): The marker type that represents the family.
+    ///
+    /// Expresses:
+    /// * If successful, the family's identifier within Ok().
+    /// * If the indicated family has not been registered, Err(RegistryError::UnregisteredType).
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_family_identifier_of<F>() -> Result<TypeIdentifier, RegistryError>
+        where F: ?Sized + 'static
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        match instance.family_map.get(&TypeId::of::<F>())
+        {
+            Some(family_record) => Ok(family_record.type_identifier),
+            None => Err(RegistryError::UnregisteredType { type_name: any::type_name::<F>() })
+        }
+    }
+
+    ///
+    /// family_includes_any: Determines whether any of the specified types is a registered member
+    ///                      of the generic family with the specified identifier.
+    ///
+    /// Parameters:
+    /// * family_identifier (TypeIdentifier): The identifier of the family to test against.
+    /// * type_identifiers (&[TypeIdentifier]): The identifiers of the types being tested.
+    ///
+    /// Expresses:
+    /// * If family_identifier identifies a registered generic family and at least one of the
+    ///   specified types is a member of that family, expresses true.
+    /// * Otherwise, expresses false.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_family_includes_any() expresses an error.
+    ///
+    /// Notes:
+    /// * ConcreteDivinator::is() and implements() answer the same question without acquiring the
+    ///   TypeRegistry's lock, via family_of().
+    ///
+    pub fn family_includes_any(family_identifier: TypeIdentifier,
+                               type_identifiers: &[TypeIdentifier]) -> bool
+    {
+        TypeRegistry::try_family_includes_any(family_identifier, type_identifiers).unwrap_or_else(
+          |error| panic!("TypeRegistry::family_includes_any(): {}", error))
+    }
+
+    ///
+    /// try_family_includes_any: Attempts to determine whether any of the specified types is a
+    ///                          registered member of the generic family with the specified
+    ///                          identifier.
+    ///
+    /// Parameters:
+    /// * family_identifier (TypeIdentifier): The identifier of the family to test against.
+    /// * type_identifiers (&[TypeIdentifier]): The identifiers of the types being tested.
+    ///
+    /// Expresses:
+    /// * If successful, the answer as described for family_includes_any() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_family_includes_any(family_identifier: TypeIdentifier,
+                                   type_identifiers: &[TypeIdentifier]) ->
+      Result<bool, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+
+        // Only families can include anything....
+        match instance.uuid_map.get(&family_identifier.type_uuid)
+                      .and_then(|family_id| instance.family_map.get(family_id))
+        {
+            Some(family_record) if family_record.type_identifier == family_identifier => {},
+            _ => return Ok(false)
+        }
+
+        Ok(type_identifiers.iter().any(|type_identifier|
+        {
+            instance.record_of(*type_identifier)
+                    .is_some_and(|type_record| type_record.family == Some(family_identifier))
+        }))
+    }
+
+    ///
+    /// types: Enumerates all registered RDH interfaces and types.
+    ///
+    /// Expresses: The records of all registered interfaces and types (but not generic families),
+    ///            ordered by type UUID.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_types() expresses an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///    for type_record in TypeRegistry::types()
+    ///    {
+    ///        println!("{}: {:?}", type_record.type_name(), type_record.kind());
+    ///    }
+    /// ```
+    ///
+    pub fn types() -> Vec<TypeRecord>
+    {
+        TypeRegistry::try_types().unwrap_or_else(
+          |error| panic!("TypeRegistry::types(): {}", error))
+    }
+
+    ///
+    /// try_types: Attempts to enumerate all registered RDH interfaces and types.
+    ///
+    /// Expresses:
+    /// * If successful, the records described for types() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_types() -> Result<Vec<TypeRecord>, RegistryError>
+    {
+        TypeRegistry::try_collect_records(|_| true)
+    }
+
+    ///
+    /// types_in_library: Enumerates the RDH interfaces and types registered by the indicated
+    ///                   library.
+    ///
+    /// Parameters:
+    /// * library_identifier (LibraryIdentifier): The identifier of the library whose types to
+    ///                                           enumerate.
+    ///
+    /// Expresses: The records of all interfaces and types (but not generic families) defined
+    ///            within the indicated library, ordered by type UUID.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_types_in_library() expresses an error.
+    ///
+    pub fn types_in_library(library_identifier: LibraryIdentifier) -> Vec<TypeRecord>
+    {
+        TypeRegistry::try_types_in_library(library_identifier).unwrap_or_else(
+          |error| panic!("TypeRegistry::types_in_library(): {}", error))
+    }
+
+    ///
+    /// try_types_in_library: Attempts to enumerate the RDH interfaces and types registered by the
+    ///                       indicated library.
+    ///
+    /// Parameters:
+    /// * library_identifier (LibraryIdentifier): The identifier of the library whose types to
+    ///                                           enumerate.
+    ///
+    /// Expresses:
+    /// * If successful, the records described for types_in_library() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_types_in_library(library_identifier: LibraryIdentifier) ->
+      Result<Vec<TypeRecord>, RegistryError>
+    {
+        TypeRegistry::try_collect_records(
+          |type_record| type_record.library_identifier() == library_identifier)
+    }
+
+    ///
+    /// interfaces: Enumerates all registered RDH public interfaces.
+    ///
+    /// Expresses: The records of all registered public interfaces, ordered by type UUID.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_interfaces() expresses an error.
+    ///
+    pub fn interfaces() -> Vec<TypeRecord>
+    {
+        TypeRegistry::try_interfaces().unwrap_or_else(
+          |error| panic!("TypeRegistry::interfaces(): {}", error))
+    }
+
+    ///
+    /// try_interfaces: Attempts to enumerate all registered RDH public interfaces.
+    ///
+    /// Expresses:
+    /// * If successful, the records described for interfaces() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_interfaces() -> Result<Vec<TypeRecord>, RegistryError>
+    {
+        TypeRegistry::try_collect_records(|type_record| type_record.kind == TypeKind::Interface)
+    }
+
+    ///
+    /// families: Enumerates all registered generic families.
+    ///
+    /// Expresses: The records of all registered generic families, ordered by family UUID.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_families() expresses an error.
+    ///
+    pub fn families() -> Vec<TypeRecord>
+    {
+        TypeRegistry::try_families().unwrap_or_else(
+          |error| panic!("TypeRegistry::families(): {}", error))
+    }
+
+    ///
+    /// try_families: Attempts to enumerate all registered generic families.
+    ///
+    /// Expresses:
+    /// * If successful, the records described for families() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_families() -> Result<Vec<TypeRecord>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        let mut family_records: Vec<TypeRecord> = instance.family_map.values().cloned().collect();
+        family_records.sort_by_key(|family_record| family_record.type_identifier.type_uuid);
+        Ok(family_records)
+    }
+
+    ///
+    /// record_of_uuid: Looks up the RDH interface, type, or generic family to which the specified
+    ///                 UUID has been assigned.
+    ///
+    /// Parameters:
+    /// * type_uuid (u128): The UUID to look up.
+    ///
+    /// Expresses:
+    /// * If the UUID has been registered, the corresponding record (from which its name and
+    ///   TypeIdentifier may be obtained) within Some().
+    /// * Otherwise, expresses None.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_record_of_uuid() expresses an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///    if let Some(type_record) = TypeRegistry::record_of_uuid(reported_uuid)
+    ///    {
+    ///        println!("Plugin reported a {}.", type_record.type_name());
+    ///    }
+    /// ```
+    ///
+    pub fn record_of_uuid(type_uuid: u128) -> Option<TypeRecord>
+    {
+        TypeRegistry::try_record_of_uuid(type_uuid).unwrap_or_else(
+          |error| panic!("TypeRegistry::record_of_uuid(): {}", error))
+    }
+
+    ///
+    /// try_record_of_uuid: Attempts to look up the RDH interface, type, or generic family to which
+    ///                     the specified UUID has been assigned.
+    ///
+    /// Parameters:
+    /// * type_uuid (u128): The UUID to look up.
+    ///
+    /// Expresses:
+    /// * If successful, the answer as described for record_of_uuid() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_record_of_uuid(type_uuid: u128) -> Result<Option<TypeRecord>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        Ok(instance.uuid_map.get(&type_uuid)
+                   .and_then(|type_id| instance.type_map.get(type_id)
+                                               .or_else(|| instance.family_map.get(type_id)))
+                   .cloned())
+    }
+
+    ///
+    /// library_record_of: Looks up the metadata registered for the indicated RDH library.
+    ///
+    /// Parameters:
+    /// * library_identifier (LibraryIdentifier): The identifier of the library to look up.
+    ///
+    /// Expresses:
+    /// * If the library has been registered, its record within Some().
+    /// * Otherwise, expresses None.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_library_record_of() expresses an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///    let library_identifier = TypeRegistry::library_identifier_of::<Checkbox>();
+    ///    if let Some(library_record) = TypeRegistry::library_record_of(library_identifier)
+    ///    {
+    ///        println!("Checkbox ({})", library_record);  // e.g. "rdh_extension_example 0.5.0"
+    ///    }
+    /// ```
+    ///
+    pub fn library_record_of(library_identifier: LibraryIdentifier) -> Option<LibraryRecord>
+    {
+        TypeRegistry::try_library_record_of(library_identifier).unwrap_or_else(
+          |error| panic!("TypeRegistry::library_record_of(): {}", error))
+    }
+
+    ///
+    /// try_library_record_of: Attempts to look up the metadata registered for the indicated RDH
+    ///                        library.
+    ///
+    /// Parameters:
+    /// * library_identifier (LibraryIdentifier): The identifier of the library to look up.
+    ///
+    /// Expresses:
+    /// * If successful, the answer as described for library_record_of() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_library_record_of(library_identifier: LibraryIdentifier) ->
+      Result<Option<LibraryRecord>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        Ok(instance.library_map.get(&library_identifier).cloned())
+    }
+
+    ///
+    /// libraries: Enumerates all registered RDH libraries.
+    ///
+    /// Expresses: The records of all registered libraries, ordered by library UUID.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_libraries() expresses an error.
+    ///
+    pub fn libraries() -> Vec<LibraryRecord>
+    {
+        TypeRegistry::try_libraries().unwrap_or_else(
+          |error| panic!("TypeRegistry::libraries(): {}", error))
+    }
+
+    ///
+    /// try_libraries: Attempts to enumerate all registered RDH libraries.
+    ///
+    /// Expresses:
+    /// * If successful, the records described for libraries() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_libraries() -> Result<Vec<LibraryRecord>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        let mut library_records: Vec<LibraryRecord> =
+          instance.library_map.values().cloned().collect();
+        library_records.sort_by_key(|library_record| library_record.library_identifier.uuid);
+        Ok(library_records)
+    }
+
+    ///
+    /// parent_of: Retrieves the direct parent concrete type of the indicated concrete type.
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the concrete type whose parent to
+    ///                                     retrieve.
+    ///
+    /// Expresses:
+    /// * If the indicated type is registered and its parent is registered, the parent's identifier
+    ///   within Some().
+    /// * Otherwise (e.g. for the hierarchy root or for public interfaces), None.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_parent_of() expresses an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///    // Expresses Some(<the TypeIdentifier for Vehicle>).
+    ///    let parent = TypeRegistry::parent_of(TypeRegistry::type_identifier_of::<Car>());
+    /// ```
+    ///
+    pub fn parent_of(type_identifier: TypeIdentifier) -> Option<TypeIdentifier>
+    {
+        TypeRegistry::try_parent_of(type_identifier).unwrap_or_else(
+          |error| panic!("TypeRegistry::parent_of(): {}", error))
+    }
+
+    ///
+    /// try_parent_of: Attempts to retrieve the direct parent concrete type of the indicated
+    ///                concrete type.
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the concrete type whose parent to
+    ///                                     retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the answer as described for parent_of() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_parent_of(type_identifier: TypeIdentifier) ->
+      Result<Option<TypeIdentifier>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        Ok(instance.record_of(type_identifier)
+                   .and_then(|type_record| instance.parent_record_of(type_record))
+                   .map(|parent_record| parent_record.type_identifier))
+    }
+
+    ///
+    /// ancestors_of: Retrieves all ancestor concrete types of the indicated concrete type.
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the concrete type whose ancestors to
+    ///                                     retrieve.
+    ///
+    /// Expresses: The identifiers of all registered ancestors, nearest (i.e. the direct parent)
+    ///            first and the hierarchy root last. Empty for the hierarchy root, for public
+    ///            interfaces, and for unregistered types.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_ancestors_of() expresses an error.
+    ///
+    pub fn ancestors_of(type_identifier: TypeIdentifier) -> Vec<TypeIdentifier>
+    {
+        TypeRegistry::try_ancestors_of(type_identifier).unwrap_or_else(
+          |error| panic!("TypeRegistry::ancestors_of(): {}", error))
+    }
+
+    ///
+    /// try_ancestors_of: Attempts to retrieve all ancestor concrete types of the indicated
+    ///                   concrete type.
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the concrete type whose ancestors to
+    ///                                     retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the identifiers described for ancestors_of() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_ancestors_of(type_identifier: TypeIdentifier) ->
+      Result<Vec<TypeIdentifier>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        Ok(match instance.record_of(type_identifier)
+        {
+            Some(type_record) => instance.ancestor_records_of(type_record)
+                                         .iter()
+                                         .map(|ancestor_record| ancestor_record.type_identifier)
+                                         .collect(),
+            None => Vec::new()
+        })
+    }
+
+    ///
+    /// inherits_from: Determines whether the indicated concrete type encompasses, directly or
+    ///                indirectly, the indicated ancestor concrete type (or a member of the
+    ///                indicated generic family).
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the (descendant) concrete type.
+    /// * ancestor_identifier (TypeIdentifier): The identifier of the ancestor concrete type or
+    ///                                         generic family to look for.
+    ///
+    /// Expresses:
+    /// * If the ancestor is found within the type's registered ancestor chain, expresses true.
+    /// * Otherwise (including when both identifiers are the same), expresses false.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_inherits_from() expresses an error.
+    ///
+    /// Notes:
+    /// * This function is primarily used implicitly by ConcreteDivinator::is_a().
+    ///
+    pub fn inherits_from(type_identifier: TypeIdentifier,
+                         ancestor_identifier: TypeIdentifier) -> bool
+    {
+        TypeRegistry::try_inherits_from(type_identifier, ancestor_identifier).unwrap_or_else(
+          |error| panic!("TypeRegistry::inherits_from(): {}", error))
+    }
+
+    ///
+    /// try_inherits_from: Attempts to determine whether the indicated concrete type encompasses,
+    ///                    directly or indirectly, the indicated ancestor concrete type (or a
+    ///                    member of the indicated generic family).
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the (descendant) concrete type.
+    /// * ancestor_identifier (TypeIdentifier): The identifier of the ancestor concrete type or
+    ///                                         generic family to look for.
+    ///
+    /// Expresses:
+    /// * If successful, the answer as described for inherits_from() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_inherits_from(type_identifier: TypeIdentifier,
+                             ancestor_identifier: TypeIdentifier) -> Result<bool, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        Ok(instance.record_of(type_identifier).is_some_and(|type_record|
+        {
+            instance.ancestor_records_of(type_record).iter().any(|ancestor_record|
+            {
+                ancestor_record.type_identifier == ancestor_identifier ||
+                ancestor_record.family == Some(ancestor_identifier)
+            })
+        }))
+    }
+
+    ///
+    /// descendants_of: Retrieves all registered concrete types that (directly or indirectly)
+    ///                 encompass the indicated concrete type.
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the concrete type whose descendants
+    ///                                     to retrieve.
+    ///
+    /// Expresses: The identifiers of all registered descendants, ordered by type UUID.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_descendants_of() expresses an error.
+    ///
+    pub fn descendants_of(type_identifier: TypeIdentifier) -> Vec<TypeIdentifier>
+    {
+        TypeRegistry::try_descendants_of(type_identifier).unwrap_or_else(
+          |error| panic!("TypeRegistry::descendants_of(): {}", error))
+    }
+
+    ///
+    /// try_descendants_of: Attempts to retrieve all registered concrete types that (directly or
+    ///                     indirectly) encompass the indicated concrete type.
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the concrete type whose descendants
+    ///                                     to retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the identifiers described for descendants_of() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_descendants_of(type_identifier: TypeIdentifier) ->
+      Result<Vec<TypeIdentifier>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        let mut descendants: Vec<TypeIdentifier> =
+          instance.type_map.values()
+                  .filter(|type_record|
+                          {
+                              instance.ancestor_records_of(type_record)
+                                      .iter()
+                                      .any(|ancestor_record|
+                                             ancestor_record.type_identifier == type_identifier)
+                          })
+                  .map(|type_record| type_record.type_identifier)
+                  .collect();
+        descendants.sort_by_key(|descendant| descendant.type_uuid);
+        Ok(descendants)
+    }
+
+    ///
+    /// interfaces_of: Retrieves the full set of public interfaces implemented by the indicated
+    ///                concrete type (or encompassed by the indicated public interface).
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the type whose interfaces to
+    ///                                     retrieve.
+    ///
+    /// Expresses: The identifiers of all registered interfaces, the introduced interface (if any)
+    ///            first and IConstruct last. Empty for unregistered types.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_interfaces_of() expresses an error.
+    ///
+    pub fn interfaces_of(type_identifier: TypeIdentifier) -> Vec<TypeIdentifier>
+    {
+        TypeRegistry::try_interfaces_of(type_identifier).unwrap_or_else(
+          |error| panic!("TypeRegistry::interfaces_of(): {}", error))
+    }
+
+    ///
+    /// try_interfaces_of: Attempts to retrieve the full set of public interfaces implemented by the
+    ///                    indicated concrete type (or encompassed by the indicated public
+    ///                    interface).
+    ///
+    /// Parameters:
+    /// * type_identifier (TypeIdentifier): The identifier of the type whose interfaces to
+    ///                                     retrieve.
+    ///
+    /// Expresses:
+    /// * If successful, the identifiers described for interfaces_of() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_interfaces_of(type_identifier: TypeIdentifier) ->
+      Result<Vec<TypeIdentifier>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        Ok(match instance.record_of(type_identifier)
+        {
+            Some(type_record) => type_record.interfaces
+                                            .iter()
+                                            .filter_map(|interface_id|
+                                                          instance.type_map.get(interface_id))
+                                            .map(|interface_record|
+                                                   interface_record.type_identifier)
+                                            .collect(),
+            None => Vec::new()
+        })
+    }
+
+    ///
+    /// types_implementing: Retrieves all registered concrete types that implement the indicated
+    ///                     public interface.
+    ///
+    /// Parameters:
+    /// * interface_identifier (TypeIdentifier): The identifier of the public interface to look
+    ///                                          for.
+    ///
+    /// Expresses: The identifiers of all registered implementing concrete types, ordered by type
+    ///            UUID.
+    ///
+    /// Panics:
+    /// * Under any of the conditions for which try_types_implementing() expresses an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///    for car_type in TypeRegistry::types_implementing(
+    ///                      TypeRegistry::type_identifier_of::<dyn ICar>())
+    ///    {
+    ///        println!("{:?} is a kind of car.", car_type);
+    ///    }
+    /// ```
+    ///
+    pub fn types_implementing(interface_identifier: TypeIdentifier) -> Vec<TypeIdentifier>
+    {
+        TypeRegistry::try_types_implementing(interface_identifier).unwrap_or_else(
+          |error| panic!("TypeRegistry::types_implementing(): {}", error))
+    }
+
+    ///
+    /// try_types_implementing: Attempts to retrieve all registered concrete types that implement
+    ///                         the indicated public interface.
+    ///
+    /// Parameters:
+    /// * interface_identifier (TypeIdentifier): The identifier of the public interface to look
+    ///                                          for.
+    ///
+    /// Expresses:
+    /// * If successful, the identifiers described for types_implementing() within Ok().
+    /// * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    ///   Err(RegistryError::PoisonedLock).
+    ///
+    pub fn try_types_implementing(interface_identifier: TypeIdentifier) ->
+      Result<Vec<TypeIdentifier>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        let interface_id = match instance.uuid_map.get(&interface_identifier.type_uuid)
+        {
+            Some(interface_id) if instance.record_of(interface_identifier).is_some() =>
+              *interface_id,
+            _ => return Ok(Vec::new())
+        };
+        let mut implementers: Vec<TypeIdentifier> =
+          instance.type_map.values()
+                  .filter(|type_record| type_record.kind == TypeKind::Concrete &&
+                                        type_record.interfaces.contains(&interface_id))
+                  .map(|type_record| type_record.type_identifier)
+                  .collect();
+        implementers.sort_by_key(|implementer| implementer.type_uuid);
+        Ok(implementers)
+    }
+
+    // *** Private functions ***
+
+    //
+    // record_of: Retrieves the record of the RDH interface or type with the specified identifier.
+    //
+    // Parameters:
+    // * type_identifier (TypeIdentifier): The identifier of the type whose record to retrieve.
+    //
+    // Expresses:
+    // * If the type is registered (within the library the identifier indicates), a reference to its
+    //   record within Some().
+    // * Otherwise, None.
+    //
+    fn record_of(&self, type_identifier: TypeIdentifier) -> Option<&TypeRecord>
+    {
+        self.uuid_map.get(&type_identifier.type_uuid)
+                     .and_then(|type_id| self.type_map.get(type_id))
+                     .filter(|type_record| type_record.type_identifier == type_identifier)
+    }
+
+    //
+    // parent_record_of: Retrieves the record of the direct parent of the specified type record.
+    //
+    // Parameters:
+    // * type_record (&TypeRecord): The record of the type whose parent to retrieve.
+    //
+    // Expresses:
+    // * If the type has a registered parent, a reference to the parent's record within Some().
+    // * Otherwise, None.
+    //
+    fn parent_record_of(&self, type_record: &TypeRecord) -> Option<&TypeRecord>
+    {
+        type_record.parent.and_then(|parent_id| self.type_map.get(&parent_id))
+    }
+
+    //
+    // ancestor_records_of: Retrieves the records of all ancestors of the specified type record.
+    //
+    // Parameters:
+    // * type_record (&TypeRecord): The record of the type whose ancestors to retrieve.
+    //
+    // Expresses: References to the records of all registered ancestors, nearest first.
+    //
+    // Notes:
+    // * The walk is bounded by the number of registered types, guarding against (malformed)
+    //   cyclic lineage.
+    //
+    fn ancestor_records_of(&self, type_record: &TypeRecord) -> Vec<&TypeRecord>
+    {
+        let mut ancestor_records = Vec::new();
+        let mut current_record = self.parent_record_of(type_record);
+        while let Some(ancestor_record) = current_record
+        {
+            if ancestor_records.len() >= self.type_map.len()
+            {
+                break;
+            }
+            ancestor_records.push(ancestor_record);
+            current_record = self.parent_record_of(ancestor_record);
+        }
+        ancestor_records
+    }
+
+    //
+    // try_accessor_path: Attempts to determine the sequence of parent accessors that leads from
+    //                    the indicated concrete type to the indicated ancestor concrete type.
+    //
+    // Parameters:
+    // * type_identifier (TypeIdentifier): The identifier of the (descendant) concrete type.
+    // * ancestor_identifier (TypeIdentifier): The identifier of the ancestor concrete type.
+    //
+    // Expresses:
+    // * If successful, and the ancestor is the type itself or is found within its ancestor chain,
+    //   the parent accessors to apply (nearest first) within Ok(Some()).
+    // * If successful, but the ancestor is not found, Ok(None).
+    // * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    //   Err(RegistryError::PoisonedLock).
+    //
+    // Notes:
+    // * The walk is bounded by the number of registered types, guarding against (malformed)
+    //   cyclic lineage.
+    //
+    fn try_accessor_path(type_identifier: TypeIdentifier,
+                         ancestor_identifier: TypeIdentifier) ->
+      Result<Option<Vec<ParentAccessors>>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        let mut accessor_path = Vec::new();
+        let mut current_id = instance.uuid_map.get(&type_identifier.type_uuid)
+                                     .copied()
+                                     .filter(|_| instance.record_of(type_identifier).is_some());
+        while let Some(type_id) = current_id
+        {
+            let type_record = match instance.type_map.get(&type_id)
+            {
+                Some(type_record) => type_record,
+                None => break
+            };
+            if type_record.type_identifier == ancestor_identifier
+            {
+                return Ok(Some(accessor_path));
+            }
+            if accessor_path.len() >= instance.type_map.len()
+            {
+                break;
+            }
+            match instance.accessor_map.get(&type_id)
+            {
+                Some(parent_accessors) => accessor_path.push(*parent_accessors),
+                None => break
+            }
+            current_id = type_record.parent;
+        }
+        Ok(None)
+    }
+
+    //
+    // try_collect_records: Attempts to collect the records of all registered RDH interfaces and
+    //                      types that satisfy the specified filter.
+    //
+    // Parameters:
+    // * filter (impl Fn(&TypeRecord) -> bool): Determines whether each record is collected.
+    //
+    // Expresses:
+    // * If successful, the collected records, ordered by type UUID, within Ok().
+    // * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    //   Err(RegistryError::PoisonedLock).
+    //
+    fn try_collect_records(filter: impl Fn(&TypeRecord) -> bool) ->
+      Result<Vec<TypeRecord>, RegistryError>
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        let mut type_records: Vec<TypeRecord> =
+          instance.type_map.values().filter(|type_record| filter(type_record)).cloned().collect();
+        type_records.sort_by_key(|type_record| type_record.type_identifier.type_uuid);
+        Ok(type_records)
+    }
+
+    //
+    // insert_type_record: Records the indicated RDH interface or type within this TypeRegistry
+    //                     instance.
+    //
+    // Generic parameters:
+    // * T (IConstruct + Lineage + ?Sized + 'static): The RDH type to record.
+    //
+    // Parameters:
+    // * type_identifier (TypeIdentifier): The identifier to assign to the type.
+    // * family (Option<TypeIdentifier>): The identifier of the type's generic family, if any.
+    //
+    // Expresses:
+    // * If successful, Ok(()).
+    // * If the indicated type has already been registered, Err(RegistryError::DuplicateType).
+    // * If the specified type UUID has already been registered to a different type,
+    //   Err(RegistryError::DuplicateUuid).
+    //
+    fn insert_type_record<T>(&mut self,
+                             type_identifier: TypeIdentifier,
+                             family: Option<TypeIdentifier>) -> Result<(), RegistryError>
+        where T: IConstruct + Lineage + ?Sized + 'static
+    {
+        let type_id = TypeId::of::<T>();
+        let type_name = any::type_name::<T>();
+
+        // Make sure the type hasn't already been registered....
+        if self.type_map.contains_key(&type_id) == true ||
+           self.family_map.contains_key(&type_id) == true
+        {
+            return Err(RegistryError::DuplicateType { type_name });
+        }
+
+        // Also make sure the type's UUID hasn't already been registered....
+        if self.uuid_map.contains_key(&type_identifier.type_uuid) == true
+        {
+            return Err(RegistryError::DuplicateUuid { uuid: type_identifier.type_uuid, type_name });
+        }
+
+        self.uuid_map.insert(type_identifier.type_uuid, type_id);
+        if let Some(parent_accessors) = T::parent_accessors()
+        {
+            self.accessor_map.insert(type_id, parent_accessors);
+        }
+        self.type_map.insert(type_id, TypeRecord
+                                      {
+                                          type_identifier,
+                                          type_name,
+                                          kind: T::KIND,
+                                          family,
+                                          parent: T::parent_type_id(),
+                                          interfaces: T::interface_type_ids()
+                                      });
+        Ok(())
+    }
+
+    //
+    // try_identity_of: Attempts to retrieve the type identifier and generic family identifier (if
+    //                  any) assigned to the indicated RDH type, from its identity cache when
+    //                  possible.
+    //
+    // Generic parameters:
+    // * T (IConstruct + Lineage + ?Sized + 'static): The RDH type whose identity to retrieve.
+    //
+    // Expresses:
+    // * If successful, the type's identifier and family identifier within Ok().
+    // * Otherwise, any error expressed by try_type_record_of().
+    //
+    fn try_identity_of<T>() -> Result<(TypeIdentifier, Option<TypeIdentifier>), RegistryError>
+        where T: IConstruct + Lineage + ?Sized + 'static
+    {
+        // Registrations are permanent, so once resolved, a type's identity never changes (unless
+        // it's registered again within a RegistryScope, which the cache mustn't reflect).
+        let identity_cache = match RegistryScope::is_active()
+        {
+            true => None,
+            false => T::identity_cache()
+        };
+        if let Some(identity) = identity_cache.and_then(|cache| cache.get())
+        {
+            return Ok(*identity);
+        }
+        let identity = TypeRegistry::try_type_record_of::<T, _>(
+          |type_record| (type_record.type_identifier, type_record.family))?;
+        if let Some(cache) = identity_cache
+        {
+            cache.get_or_init(|| identity);
+        }
+        Ok(identity)
+    }
+
+    //
+    // try_type_record_of: Attempts to read a value from the record registered for the indicated
+    //                     RDH type.
+    //
+    // Generic parameters:
+    // * T (IConstruct + ?Sized + 'static): The RDH type whose record to read.
+    // * V: The type of value to read from the record.
+    //
+    // Parameters:
+    // * read (impl FnOnce(&TypeRecord) -> V): Reads the desired value from the type's record.
+    //
+    // Expresses:
+    // * If successful, the value read within Ok().
+    // * If the indicated type has not been registered, Err(RegistryError::UnregisteredType).
+    // * If the lock that guards the TypeRegistry singleton instance has been poisoned,
+    //   Err(RegistryError::PoisonedLock).
+    //
+    fn try_type_record_of<T, V>(read: impl FnOnce(&TypeRecord) -> V) -> Result<V, RegistryError>
+        where T: IConstruct + ?Sized + 'static
+    {
+        let rwlock = TypeRegistry::rwlock();
+        let instance = rwlock.read()?;
+        match instance.type_map.get(&TypeId::of::<T>())
+        {
+            Some(type_record) => Ok(read(type_record)),
+            None => Err(RegistryError::UnregisteredType { type_name: any::type_name::<T>() })
+        }
+    }
+
+    //
+    // empty: Creates an empty TypeRegistry instance, guarded by a new FreezableLock.
+    //
+    fn empty() -> FreezableLock<TypeRegistry>
+    {
+        FreezableLock::new("TypeRegistry", TypeRegistry
+                                           {
+                                               type_map: HashMap::new(),
+                                               family_map: HashMap::new(),
+                                               uuid_map: HashMap::new(),
+                                               accessor_map: HashMap::new(),
+                                               library_map: HashMap::new(),
+                                               freezers: Vec::new()
+                                           })
+    }
+
+    //
+    // rwlock: Obtains the reader/writer lock that guards access to the singleton TypeRegistry
+    //         instance (creating both when necessary), or to the TypeRegistry instance of the
+    //         RegistryScope current on this thread, if any.
+    //
+    // Expresses: The lock that guards the singleton (or scoped) instance.
+    //
+    fn rwlock() -> RegistryLock<TypeRegistry>
+    {
+        // Implementation notes: Structs may not contain static fields, so we have to declare the
+        // singleton instance and the rwlock that guards it here (which has its advantages).
+
+        // The FreezableLock that guards the singleton instance of the TypeRegistry struct, created
+        // the first time it's needed (while all other calling threads are blocked).
+        static RWLOCK: LazyLock<FreezableLock<TypeRegistry>> = LazyLock::new(TypeRegistry::empty);
+
+        RegistryScope::current_lock(TypeRegistry::empty).unwrap_or_else(
+          || RegistryLock::Global(&RWLOCK))
+    }
+}
+
+///
+/// TypeRecord: The information recorded within the TypeRegistry for each registered RDH type or
+///             generic family.
+///
+/// Notes:
+/// * TypeRecords expressed by TypeRegistry's enumeration functions are snapshots; they do not
+///   reflect registrations made after they were obtained.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeRecord
+{
+    // The type's identifier.
+    type_identifier: TypeIdentifier,
+
+    // The type's display name, as reported by std::any::type_name() at registration.
+    type_name: &'static str,
+
+    // The kind of type recorded.
+    kind: TypeKind,
+
+    // The identifier of the generic family to which the type belongs, if any.
+    family: Option<TypeIdentifier>,
+
+    // The Rust type id of the type's direct parent concrete type, if any.
+    parent: Option<TypeId>,
+
+    // The Rust type ids of the public interfaces the type implements (or, for interfaces,
+    // encompasses).
+    interfaces: Vec<TypeId>
+}
+impl TypeRecord
+{
+    ///
+    /// type_identifier: Retrieves the recorded type's identifier.
+    ///
+    pub fn type_identifier(&self) -> TypeIdentifier
+    {
+        self.type_identifier
+    }
+
+    ///
+    /// library_identifier: Retrieves the identifier of the library in which the recorded type is
+    ///                     defined.
+    ///
+    pub fn library_identifier(&self) -> LibraryIdentifier
+    {
+        self.type_identifier.library_identifier
+    }
+
+    ///
+    /// type_name: Retrieves the recorded type's display name.
+    ///
+    /// Notes:
+    /// * Display names are suitable for diagnostics, but must never be used to identify types.
+    ///
+    pub fn type_name(&self) -> &'static str
+    {
+        self.type_name
+    }
+
+    ///
+    /// kind: Retrieves the kind of type recorded (public interface, concrete type, or generic
+    ///       family).
+    ///
+    pub fn kind(&self) -> TypeKind
+    {
+        self.kind
+    }
+
+    ///
+    /// family: Retrieves the identifier of the generic family to which the recorded type belongs,
+    ///         if any.
+    ///
+    pub fn family(&self) -> Option<TypeIdentifier>
+    {
+        self.family
+    }
+}
+
+///
+/// TypeKind: Distinguishes between the kinds of entries recorded within the TypeRegistry.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TypeKind
+{
+    ///
+    /// Interface: A public interface, e.g. dyn IConstruct.
+    ///
+    Interface,
+
+    ///
+    /// Concrete: A concrete type, e.g. Construct.
+    ///
+    Concrete,
+
+    ///
+    /// Family: A generic family, represented by its marker type, e.g. PropertyFamily.
+    ///
+    Family
+}
+
+///
+/// TypeIdentifier: Represents an RDH type's identifier.
+///
+/// Notes:
+/// * TypeIdentifier instances should generally be treated as opaque blobs, requested only when
+///   needed and passed on blindly.
+///   * However, as they never change, they may be persisted (e.g. to key caches, logs, or save
+///     files) via their Display text, e.g. "2f8a4c9e-...-5b1d@3e7f0a12-...-c6d8" (the type's UUID
+///     followed by its library's UUID, in hyphenated hex), or via to_bytes().
+///   * Their ordering (by type UUID, and then by library UUID) is arbitrary but stable.
+//  * That UUIDs can accomodate up to 18.5 quintillion values is not meant to recommend this as a
+//    reasonable number of hirarchy types for any application! :)
+//    * Instead, using u128s for identifiers is intended to ease parallel development and
+//      integration work across large teams with multiple dependencies.
+//    * Just as with UUIDs in other languages, randomly selected u128 values are exceedingly
+//      unlikely to conflict.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TypeIdentifier
+{
+    // The unique identifier for the RDH type.
+    type_uuid: u128,
+
+    // The unique identifier for the library in which the RDH type is defined.
+    library_identifier: LibraryIdentifier
+}
+impl TypeIdentifier
+{
+    // *** Public constants ***
+
+    ///
+    /// ENCODED_LENGTH: The length, in bytes, of a TypeIdentifier's byte encoding (see to_bytes()).
+    ///
+    pub const ENCODED_LENGTH: usize = 32;
+
+    // *** Public functions ***
+
+    ///
+    /// new: Creates the identifier for the RDH type with the specified UUID, defined within the
+    ///      indicated library.
+    ///
+    /// Parameters:
+    /// * type_uuid (u128): The unique identifier of the type, as specified when registering it.
+    /// * library_identifier (LibraryIdentifier): The identifier of the library in which the type is
+    ///                                           defined.
+    ///
+    /// Notes:
+    /// * Creating an identifier doesn't register anything; to identify a registered type, prefer
+    ///   TypeRegistry::type_identifier_of().
+    ///
+    pub const fn new(type_uuid: u128, library_identifier: LibraryIdentifier) -> TypeIdentifier
+    {
+        TypeIdentifier { type_uuid, library_identifier }
+    }
+
+    ///
+    /// type_uuid: Retrieves the unique identifier of the identified type.
+    ///
+    pub const fn type_uuid(&self) -> u128
+    {
+        self.type_uuid
+    }
+
+    ///
+    /// library_identifier: Retrieves the identifier of the library in which the identified type is
+    ///                     defined.
+    ///
+    pub const fn library_identifier(&self) -> LibraryIdentifier
+    {
+        self.library_identifier
+    }
+
+    ///
+    /// to_bytes: Encodes this identifier as the type's UUID followed by its library's UUID, each in
+    ///           big-endian (i.e. RFC 4122) byte order.
+    ///
+    /// Notes:
+    /// * This encoding is stable across rdh versions and platforms; see from_bytes().
+    ///
+    pub const fn to_bytes(&self) -> [u8; TypeIdentifier::ENCODED_LENGTH]
+    {
+        let type_bytes = self.type_uuid.to_be_bytes();
+        let library_bytes = self.library_identifier.to_bytes();
+        let mut bytes = [0u8; TypeIdentifier::ENCODED_LENGTH];
+        let mut index = 0;
+        while index < LibraryIdentifier::ENCODED_LENGTH
+        {
+            bytes[index] = type_bytes[index];
+            bytes[LibraryIdentifier::ENCODED_LENGTH + index] = library_bytes[index];
+            index += 1;
+        }
+        bytes
+    }
+
+    ///
+    /// from_bytes: Decodes the identifier encoded by to_bytes().
+    ///
+    /// Parameters:
+    /// * bytes ([u8; 32]): The identifier's byte encoding.
+    ///
+    pub const fn from_bytes(bytes: [u8; TypeIdentifier::ENCODED_LENGTH]) -> TypeIdentifier
+    {
+        let mut type_bytes = [0u8; LibraryIdentifier::ENCODED_LENGTH];
+        let mut library_bytes = [0u8; LibraryIdentifier::ENCODED_LENGTH];
+        let mut index = 0;
+        while index < LibraryIdentifier::ENCODED_LENGTH
+        {
+            type_bytes[index] = bytes[index];
+            library_bytes[index] = bytes[LibraryIdentifier::ENCODED_LENGTH + index];
+            index += 1;
+        }
+        TypeIdentifier
+        {
+            type_uuid: u128::from_be_bytes(type_bytes),
+            library_identifier: LibraryIdentifier::from_bytes(library_bytes)
+        }
+    }
+}
+impl fmt::Display for TypeIdentifier
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write_uuid(formatter, self.type_uuid)?;
+        write!(formatter, "@{}", self.library_identifier)
+    }
+}
+impl FromStr for TypeIdentifier
+{
+    type Err = ParseIdentifierError;
+
+    //
+    // from_str: Parses the Display text of a TypeIdentifier (hexadecimal digits may be of either
+    //           case).
+    //
+    fn from_str(text: &str) -> Result<TypeIdentifier, ParseIdentifierError>
+    {
+        let bytes = text.as_bytes();
+        if bytes.len() != 2 * UUID_TEXT_LENGTH + 1
+        {
+            return Err(ParseIdentifierError::InvalidLength { length: bytes.len() });
+        }
+        if bytes[UUID_TEXT_LENGTH] != b'@'
+        {
+            return Err(ParseIdentifierError::InvalidCharacter { position: UUID_TEXT_LENGTH });
+        }
+        Ok(TypeIdentifier
+        {
+            type_uuid: parse_uuid_at(bytes, 0)?,
+            library_identifier: LibraryIdentifier { uuid: parse_uuid_at(bytes,
+                                                                        UUID_TEXT_LENGTH + 1)? }
+        })
+    }
+}
+
+///
+/// LibraryIdentifier: The unique identifier for an RDH library.
+///
+/// Notes:
+/// * LibraryIdentifier instances should generally be treated as opaque blobs, requested only when
+///   needed and passed on blindly.
+///   * However, as they never change, they may be persisted via their Display text (the library's
+///     UUID in hyphenated hex, e.g. "3e7f0a12-9c4b-4d21-8f6e-0b5a7d93c6d8") or via to_bytes().
+//  * That UUIDs can accomodate up to 18.5 quintillion values is not meant to recommend this as a
+//    reasonable number of RDH libraries for any application! :)
+//    * Instead, using u128s for identifiers is intended to ease parallel development and
+//      integration work across large teams with multiple dependencies.
+//    * Just as with UUIDs in other languages, randomly selected u128 values are exceedingly
+//      unlikely to conflict.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LibraryIdentifier
+{
+    uuid: u128
+}
+impl LibraryIdentifier
+{
+    // *** Public constants ***
+
+    ///
+    /// ENCODED_LENGTH: The length, in bytes, of a LibraryIdentifier's byte encoding (see
+    ///                 to_bytes()).
+    ///
+    pub const ENCODED_LENGTH: usize = 16;
+
+    // *** Public functions ***
+
+    ///
+    /// from_uuid: Creates the identifier for the RDH library with the specified UUID.
+    ///
+    /// Parameters:
+    /// * library_uuid (u128): The unique identifier of the library, as specified when registering
+    ///                        its types.
+    ///
+    /// Notes:
+    /// * Used by register_types! to register downcast handlers for the library whose types it
+    ///   registers.
+    ///
+    pub const fn from_uuid(library_uuid: u128) -> LibraryIdentifier
+    {
+        LibraryIdentifier { uuid: library_uuid }
+    }
+
+    ///
+    /// uuid: Retrieves the unique identifier of the identified library.
+    ///
+    pub const fn uuid(&self) -> u128
+    {
+        self.uuid
+    }
+
+    ///
+    /// to_bytes: Encodes this identifier as the library's UUID in big-endian (i.e. RFC 4122) byte
+    ///           order.
+    ///
+    /// Notes:
+    /// * This encoding is stable across rdh versions and platforms; see from_bytes().
+    ///
+    pub const fn to_bytes(&self) -> [u8; LibraryIdentifier::ENCODED_LENGTH]
+    {
+        self.uuid.to_be_bytes()
+    }
+
+    ///
+    /// from_bytes: Decodes the identifier encoded by to_bytes().
+    ///
+    /// Parameters:
+    /// * bytes ([u8; 16]): The identifier's byte encoding.
+    ///
+    pub const fn from_bytes(bytes: [u8; LibraryIdentifier::ENCODED_LENGTH]) -> LibraryIdentifier
+    {
+        LibraryIdentifier { uuid: u128::from_be_bytes(bytes) }
+    }
+
+    // *** Private functions ***
+
+    //
+    // describe: Describes this library for diagnostics, by its registered name and version when
+    //           available (e.g. "rdh_extension_example 0.5.0"), or by its UUID otherwise.
+    //
+    fn describe(&self) -> String
+    {
+        match TypeRegistry::try_library_record_of(*self)
+        {
+            Ok(Some(library_record)) => library_record.to_string(),
+            _ => self.to_string()
+        }
+    }
+}
+impl fmt::Display for LibraryIdentifier
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write_uuid(formatter, self.uuid)
+    }
+}
+impl FromStr for LibraryIdentifier
+{
+    type Err = ParseIdentifierError;
+
+    //
+    // from_str: Parses the Display text of a LibraryIdentifier (hexadecimal digits may be of
+    //           either case).
+    //
+    fn from_str(text: &str) -> Result<LibraryIdentifier, ParseIdentifierError>
+    {
+        Ok(LibraryIdentifier { uuid: parse_uuid(text)? })
+    }
+}
+
+///
+/// LibraryRecord: The metadata recorded within the TypeRegistry for each registered RDH library.
+///
+/// Notes:
+/// * Recorded by Library::try_init() as each library is initialized.
+/// * Its Display text names the library and its version, e.g. "rdh_extension_example 0.5.0".
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LibraryRecord
+{
+    // The library's identifier.
+    library_identifier: LibraryIdentifier,
+
+    // The library's name.
+    name: &'static str,
+
+    // The library's version.
+    version: &'static str,
+
+    // The earliest rdh version with which the library is compatible.
+    minimum_rdh_version: &'static str,
+
+    // The identifiers of the libraries the library directly depends upon.
+    parents: Vec<LibraryIdentifier>
+}
+impl LibraryRecord
+{
+    // *** Public functions ***
+
+    ///
+    /// new: Creates a new instance of the LibraryRecord struct.
+    ///
+    /// Parameters:
+    /// * library_identifier (LibraryIdentifier): The library's identifier.
+    /// * name (&'static str): The library's name.
+    /// * version (&'static str): The library's version.
+    /// * minimum_rdh_version (&'static str): The earliest rdh version with which the library is
+    ///                                       compatible.
+    /// * parents (Vec<LibraryIdentifier>): The identifiers of the libraries the library directly
+    ///                                     depends upon.
+    ///
+    pub fn new(library_identifier: LibraryIdentifier,
+               name: &'static str,
+               version: &'static str,
+               minimum_rdh_version: &'static str,
+               parents: Vec<LibraryIdentifier>) -> LibraryRecord
+    {
+        LibraryRecord
+        {
+            library_identifier,
+            name,
+            version,
+            minimum_rdh_version,
+            parents
+        }
+    }
+
+    ///
+    /// library_identifier: Retrieves the recorded library's identifier.
+    ///
+    pub fn library_identifier(&self) -> LibraryIdentifier
+    {
+        self.library_identifier
+    }
+
+    ///
+    /// name: Retrieves the recorded library's name.
+    ///
+    pub fn name(&self) -> &'static str
+    {
+        self.name
+    }
+
+    ///
+    /// version: Retrieves the recorded library's version.
+    ///
+    pub fn version(&self) -> &'static str
+    {
+        self.version
+    }
+
+    ///
+    /// minimum_rdh_version: Retrieves the earliest rdh version with which the recorded library is
+    ///                      compatible.
+    ///
+    pub fn minimum_rdh_version(&self) -> &'static str
+    {
+        self.minimum_rdh_version
+    }
+
+    ///
+    /// parents: Retrieves the identifiers of the libraries the recorded library directly depends
+    ///          upon.
+    ///
+    pub fn parents(&self) -> &[LibraryIdentifier]
+    {
+        &self.parents
+    }
+}
+impl fmt::Display for LibraryRecord
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(formatter, "{} {}", self.name, self.version)
+    }
+}
+
+///
+/// RegistryError: Describes why a fallible TypeRegistry or downcast handler registry operation
+///                failed.
+///
+/// Notes:
+/// * Expressed by the try_* functions of TypeRegistry and of each provisioned
+///   (IConstruct)Downcaster struct, allowing hosts (e.g. plugin loaders) to report a faulty
+///   extension rather than aborting the whole process.
+/// * The corresponding panicking functions include this error's Display text within their panic
+///   messages.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RegistryError
+{
+    ///
+    /// DuplicateType: The indicated type (or generic family) has already been registered.
+    ///
+    DuplicateType { type_name: &'static str },
+
+    ///
+    /// DuplicateUuid: The specified UUID has already been registered to a different type.
+    ///
+    DuplicateUuid { uuid: u128, type_name: &'static str },
+
+    ///
+    /// DuplicateLibrary: A library with the specified UUID has already been registered.
+    ///
+    DuplicateLibrary { uuid: u128, library_name: &'static str },
+
+    ///
+    /// DuplicateHandler: The same downcast handler struct has already been registered for the
+    ///                   specified library.
+    ///
+    DuplicateHandler { handler_name: &'static str, library_identifier: LibraryIdentifier },
+
+    ///
+    /// DuplicateDerivedType: The indicated derived type has already been registered with the
+    ///                       indicated downcasting struct.
+    ///
+    DuplicateDerivedType { downcaster_name: &'static str, type_name: &'static str },
+
+    ///
+    /// UnregisteredType: The indicated type (or generic family) has not been registered.
+    ///
+    UnregisteredType { type_name: &'static str },
+
+    ///
+    /// Frozen: The indicated registry has been frozen (via TypeRegistry::freeze()) and no longer
+    ///         accepts registrations.
+    ///
+    Frozen { registry_name: &'static str },
+
+    ///
+    /// Timeout: The lock that guards the indicated registry's singleton instance couldn't be
+    ///          acquired before its timeout elapsed (see FreezableLock::DEFAULT_TIMEOUT).
+    ///
+    Timeout { registry_name: &'static str },
+
+    ///
+    /// LockRecursion: The current thread already holds the lock that guards the indicated
+    ///                registry's singleton instance, and can't also acquire it as requested (e.g.
+    ///                registering a type from within a handler invoked during a downcast).
+    ///
+    LockRecursion { registry_name: &'static str },
+
+    ///
+    /// PoisonedLock: The lock that guards a registry's singleton instance was poisoned by a thread
+    ///               that panicked while holding it.
+    ///
+    PoisonedLock
+}
+impl fmt::Display for RegistryError
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            RegistryError::DuplicateType { type_name } =>
+              write!(formatter, "Indicated type ({}) has already been registered!", type_name),
+            RegistryError::DuplicateUuid { uuid, type_name } =>
+              write!(formatter, "Specified UUID ({}) for {} already registered to a different \
+                                 type!", uuid_string(*uuid), type_name),
+            RegistryError::DuplicateLibrary { uuid, library_name } =>
+              write!(formatter, "Specified library UUID ({}) for {} has already been registered!",
+                     uuid_string(*uuid), library_name),
+            RegistryError::DuplicateHandler { handler_name, library_identifier } =>
+              write!(formatter, "This {} handler has already been registered for the specified \
+                                 library ({})!", handler_name, library_identifier.describe()),
+            RegistryError::DuplicateDerivedType { downcaster_name, type_name } =>
+              write!(formatter, "Indicated derived type ({}) has already been registered with \
+                                 {}!", type_name, downcaster_name),
+            RegistryError::UnregisteredType { type_name } =>
+              write!(formatter, "Indicated type ({}) has not been registered!", type_name),
+            RegistryError::Frozen { registry_name } =>
+              write!(formatter, "{} has been frozen and no longer accepts registrations!",
+                     registry_name),
+            RegistryError::Timeout { registry_name } =>
+              write!(formatter, "Timed out waiting to acquire the lock that guards {}!",
+                     registry_name),
+            RegistryError::LockRecursion { registry_name } =>
+              write!(formatter, "The lock that guards {} is already held by the current thread!",
+                     registry_name),
+            RegistryError::PoisonedLock =>
+              write!(formatter, "Unable to acquire registry singleton instance: lock poisoned!")
+        }
+    }
+}
+impl Error for RegistryError {}
+
+///
+/// CastError: Describes why a fallible cast (e.g. try_as_concrete() or
+///            (IConstruct)Downcaster::try_downcast()) failed.
+///
+/// Notes:
+/// * Carries the identifier of the source object's concrete type, the identifier of the requested
+///   target type (either of which is absent when that type is unregistered), and the reason the
+///   cast failed.
+/// * Its Display text names both types (as registered) along with the reason, e.g. to quickly
+///   identify a concrete type missing from a downcasting handler's include list.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CastError
+{
+    // The identifier of the source object's concrete type, if registered.
+    source_type: Option<TypeIdentifier>,
+
+    // The identifier of the requested target type, if registered.
+    target_type: Option<TypeIdentifier>,
+
+    // The reason the cast failed.
+    reason: CastFailure
+}
+impl CastError
+{
+    // *** Public functions ***
+
+    ///
+    /// new: Creates a new instance of the CastError struct.
+    ///
+    /// Parameters:
+    /// * source_type (Option<TypeIdentifier>): The identifier of the source object's concrete type
+    ///                                         (or None, if it's unregistered).
+    /// * target_type (Option<TypeIdentifier>): The identifier of the requested target type (or
+    ///                                         None, if it's unregistered).
+    /// * reason (CastFailure): The reason the cast failed.
+    ///
+    /// Notes:
+    /// * This function is primarily used implicitly by the Necromancer struct and the downcasting
+    ///   structs created by provision_transmutation!.
+    ///
+    pub fn new(source_type: Option<TypeIdentifier>, target_type: Option<TypeIdentifier>,
+               reason: CastFailure) -> CastError
+    {
+        CastError
+        {
+            source_type,
+            target_type,
+            reason
+        }
+    }
+
+    ///
+    /// from_lookups: Creates a new instance of the CastError struct from the results of looking up
+    ///               the source and target types' identifiers.
+    ///
+    /// Parameters:
+    /// * source_type (Result<TypeIdentifier, RegistryError>): The result of looking up the
+    ///                                                        identifier of the source object's
+    ///                                                        concrete type.
+    /// * target_type (Result<TypeIdentifier, RegistryError>): The result of looking up the
+    ///                                                        identifier of the requested target
+    ///                                                        type.
+    /// * reason (impl FnOnce(TypeIdentifier, TypeIdentifier) -> CastFailure): Determines the
+    ///                                                                        reason the cast
+    ///                                                                        failed, given both
+    ///                                                                        identifiers.
+    ///
+    /// Expresses:
+    /// * If both lookups succeeded, a CastError with the reason determined by reason.
+    /// * Otherwise, a CastError whose reason is the first lookup's failure (e.g.
+    ///   CastFailure::UnregisteredType).
+    ///
+    /// Notes:
+    /// * This function is primarily used implicitly by the Necromancer struct and the downcasting
+    ///   structs created by provision_transmutation!, so that casts involving unregistered types
+    ///   fail with a CastError rather than panicking.
+    ///
+    pub fn from_lookups(source_type: Result<TypeIdentifier, RegistryError>,
+                        target_type: Result<TypeIdentifier, RegistryError>,
+                        reason: impl FnOnce(TypeIdentifier, TypeIdentifier) -> CastFailure) ->
+      CastError
+    {
+        let reason = match (source_type, target_type)
+        {
+            (Ok(source_type), Ok(target_type)) => reason(source_type, target_type),
+            (Err(error), _) | (_, Err(error)) => CastFailure::from(error)
+        };
+        CastError::new(source_type.ok(), target_type.ok(), reason)
+    }
+
+    ///
+    /// source_type: Retrieves the identifier of the source object's concrete type.
+    ///
+    /// Expresses:
+    /// * If the source object's concrete type is registered, its identifier within Some().
+    /// * Otherwise, None.
+    ///
+    pub fn source_type(&self) -> Option<TypeIdentifier>
+    {
+        self.source_type
+    }
+
+    ///
+    /// target_type: Retrieves the identifier of the requested target type.
+    ///
+    /// Expresses:
+    /// * If the requested target type is registered, its identifier within Some().
+    /// * Otherwise, None.
+    ///
+    pub fn target_type(&self) -> Option<TypeIdentifier>
+    {
+        self.target_type
+    }
+
+    ///
+    /// reason: Retrieves the reason the cast failed.
+    ///
+    pub fn reason(&self) -> CastFailure
+    {
+        self.reason
+    }
+
+    // *** Private functions ***
+
+    //
+    // name_of: Retrieves the registered name of the type with the specified identifier, followed
+    //          by its library's name and version, e.g. "Checkbox (rdh_extension_example 0.5.0)",
+    //          falling back to UUIDs when no names are available.
+    //
+    fn name_of(type_identifier: Option<TypeIdentifier>) -> String
+    {
+        let type_identifier = match type_identifier
+        {
+            Some(type_identifier) => type_identifier,
+            None => return String::from("an unregistered type")
+        };
+        let type_name = match TypeRegistry::try_record_of_uuid(type_identifier.type_uuid)
+        {
+            Ok(Some(type_record)) => type_record.type_name().to_string(),
+            _ => uuid_string(type_identifier.type_uuid)
+        };
+        format!("{} ({})", type_name, type_identifier.library_identifier.describe())
+    }
+}
+impl fmt::Display for CastError
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(formatter, "Unable to cast {} to {}: {}", CastError::name_of(self.source_type),
+               CastError::name_of(self.target_type), self.reason)
+    }
+}
+impl Error for CastError {}
+
+///
+/// CastFailure: Describes the reason a fallible cast failed.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CastFailure
+{
+    ///
+    /// NotTargetType: The source object's concrete type is not the target concrete type (or does
+    ///                not implement the target public interface).
+    ///
+    NotTargetType,
+
+    ///
+    /// NoHandler: No downcast handlers whatsoever have been registered with the indicated
+    ///            downcasting struct.
+    ///
+    NoHandler { downcaster_name: &'static str },
+
+    ///
+    /// UnregisteredLibrary: The library in which the source object's concrete type is defined has
+    ///                      not registered a downcast handler with the indicated downcasting
+    ///                      struct, nor has the source object's concrete type been registered
+    ///                      with it as a derived type.
+    ///
+    UnregisteredLibrary { downcaster_name: &'static str, library_identifier: LibraryIdentifier },
+
+    ///
+    /// NotIncluded: The source library's handler does not handle the source object's concrete
+    ///              type, even though that type implements the target public interface.
+    ///              * Most likely, the type was neither registered with the downcasting struct
+    ///                via register_derived_type() nor added to the handler's include [...] list.
+    ///
+    NotIncluded { handler_name: &'static str },
+
+    ///
+    /// UnregisteredType: The indicated source or target type has not been registered, so the cast
+    ///                   couldn't be diagnosed.
+    ///
+    UnregisteredType { type_name: &'static str },
+
+    ///
+    /// RegistryUnavailable: A registry couldn't be consulted for the indicated reason (e.g. its
+    ///                      lock is already held by the current thread).
+    ///
+    RegistryUnavailable { error: RegistryError },
+
+    ///
+    /// PoisonedLock: The lock that guards a registry's singleton instance was poisoned by a thread
+    ///               that panicked while holding it.
+    ///
+    PoisonedLock
+}
+impl From<RegistryError> for CastFailure
+{
+    fn from(error: RegistryError) -> CastFailure
+    {
+        match error
+        {
+            RegistryError::UnregisteredType { type_name } =>
+              CastFailure::UnregisteredType { type_name },
+            RegistryError::PoisonedLock => CastFailure::PoisonedLock,
+            error => CastFailure::RegistryUnavailable { error }
+        }
+    }
+}
+impl fmt::Display for CastFailure
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            CastFailure::NotTargetType =>
+              write!(formatter, "Underlying concrete type is not (and does not implement) the \
+                                 target type!"),
+            CastFailure::NoHandler { downcaster_name } =>
+              write!(formatter, "No handlers have been registered with {}!", downcaster_name),
+            CastFailure::UnregisteredLibrary { downcaster_name, library_identifier } =>
+              write!(formatter, "Neither the source type nor its library ({}) has been registered \
+                                 with {}!", library_identifier.describe(), downcaster_name),
+            CastFailure::NotIncluded { handler_name } =>
+              write!(formatter, "The source type's library {} handler does not handle it (not \
+                                 registered via register_derived_type()?)!", handler_name),
+            CastFailure::UnregisteredType { type_name } =>
+              write!(formatter, "Indicated type ({}) has not been registered!", type_name),
+            CastFailure::RegistryUnavailable { error } =>
+              write!(formatter, "Unable to consult the registry: {}", error),
+            CastFailure::PoisonedLock =>
+              write!(formatter, "Unable to acquire registry singleton instance: lock poisoned!")
+        }
+    }
+}
+
+///
+/// ParseIdentifierError: Describes why a TypeIdentifier or LibraryIdentifier couldn't be parsed
+///                       from text.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseIdentifierError
+{
+    ///
+    /// InvalidLength: The text is not of the expected length (36 bytes per hyphenated UUID, plus
+    ///                one for the "@" separating a TypeIdentifier's UUIDs).
+    ///
+    InvalidLength { length: usize },
+
+    ///
+    /// InvalidCharacter: The text contains an unexpected character at the indicated byte offset
+    ///                   (e.g. a non-hexadecimal digit, or a misplaced hyphen).
+    ///
+    InvalidCharacter { position: usize }
+}
+impl fmt::Display for ParseIdentifierError
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            ParseIdentifierError::InvalidLength { length } =>
+              write!(formatter, "Identifier text has an invalid length ({} bytes)!", length),
+            ParseIdentifierError::InvalidCharacter { position } =>
+              write!(formatter, "Identifier text has an invalid character at byte offset {}!",
+                     position)
+        }
+    }
+}
+impl Error for ParseIdentifierError {}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Necromancy: The power to raise the dead.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+//
+// *** Public traits ***
+//
+
+///
+/// Necromances: The trait through which casts from (IConstruct) objects to (Construct) instances
+///              may be requested.
+///
+/// Notes:
+/// * Because Rust currently offers no way to specify a constraint that excludes concrete types,
+///   the Necromances trait is implemented for concrete types even though such functionality has no
+///   real meaning for them.
+///   * While no harm is done if a concrete type is cast to itself in this way, doing so is a waste
+///     of CPU cycles.
+///   * In addition, that such a call can be made may be confusing.
+///     * It's important to keep in mind that Necromancer will *not* cast between concrete types,
+///       even though it may appear as though it could.
+///     * Only calls such as vehicle.as_concrete::<Vehicle>() will succeed.
+///     * Other calls, such as vehicle.as_concrete::<Construct>(), will fail, even though Vehicle
+///       encompasses Construct and the corresponding upcast for their interfaces will work through
+///       Transmutation.
+///     * Please see the "Casting scenarios" section within "RDH Inheritance Model.md."
+///
+pub trait Necromances : IConstruct
+{
+    ///
+    /// as_concrete: Attempts to cast this immutable (IConstruct) object into an immutable
+    ///              (Construct) instance.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The immutable (Construct) type to which this immutable
+    ///                             (IConstruct) object will be cast.
+    ///
+    /// Expresses:
+    /// * If successful, an immutable reference to the underlying concrete (Construct) type
+    ///   instance within an Option<&T>.
+    /// * Otherwise expresses None.
+    ///
+    fn as_concrete<T>(&self) -> Option<&T> where T: IConstruct + 'static;
+
+    ///
+    /// as_concrete_mut: Attempts to cast this mutable (IConstruct) object into a mutable
+    ///                  (Construct) instance.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The mutable (Construct) type to which this mutable
+    ///                             (IConstruct) object will be cast.
+    ///
+    /// Expresses:
+    /// * If successful, a mutable reference to the underlying concrete (Construct) type instance
+    ///   within an Option<&mut T>.
+    /// * Otherwise expresses None.
+    ///
+    fn as_concrete_mut<T>(&mut self) -> Option<&mut T> where T: IConstruct + 'static;
+
+    ///
+    /// try_as_concrete: Attempts to cast this immutable (IConstruct) object into an immutable
+    ///                  (Construct) instance, describing any failure.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The immutable (Construct) type to which this immutable
+    ///                             (IConstruct) object will be cast.
+    ///
+    /// Expresses:
+    /// * If successful, an immutable reference to the underlying concrete (Construct) type
+    ///   instance within Ok().
+    /// * Otherwise, a CastError (with reason CastFailure::NotTargetType) within Err().
+    ///
+    fn try_as_concrete<T>(&self) -> Result<&T, CastError> where T: IConstruct + 'static;
+
+    ///
+    /// try_as_concrete_mut: Attempts to cast this mutable (IConstruct) object into a mutable
+    ///                      (Construct) instance, describing any failure.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The mutable (Construct) type to which this mutable
+    ///                             (IConstruct) object will be cast.
+    ///
+    /// Expresses:
+    /// * If successful, a mutable reference to the underlying concrete (Construct) type instance
+    ///   within Ok().
+    /// * Otherwise, a CastError (with reason CastFailure::NotTargetType) within Err().
+    ///
+    fn try_as_concrete_mut<T>(&mut self) -> Result<&mut T, CastError> where T: IConstruct + 'static;
+
+    ///
+    /// as_ancestor: Attempts to recover an immutable reference to the (Construct) instance of the
+    ///              indicated type that is encompassed, directly or indirectly, by this immutable
+    ///              (IConstruct) object's underlying concrete instance.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The ancestor (Construct) type to recover.
+    ///
+    /// Expresses:
+    /// * If the underlying concrete instance is a T, or encompasses a T, an immutable reference to
+    ///   that T instance within an Option<&T>.
+    /// * Otherwise expresses None.
+    ///
+    /// Notes:
+    /// * For example, for an IConstruct backed by a Checkbox, as_ancestor::<UIElement>() expresses
+    ///   the UIElement instance embedded within the Checkbox.
+    ///
+    fn as_ancestor<T>(&self) -> Option<&T> where T: IConstruct + 'static;
+
+    ///
+    /// as_ancestor_mut: Attempts to recover a mutable reference to the (Construct) instance of the
+    ///                  indicated type that is encompassed, directly or indirectly, by this mutable
+    ///                  (IConstruct) object's underlying concrete instance.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The ancestor (Construct) type to recover.
+    ///
+    /// Expresses:
+    /// * If the underlying concrete instance is a T, or encompasses a T, a mutable reference to
+    ///   that T instance within an Option<&mut T>.
+    /// * Otherwise expresses None.
+    ///
+    fn as_ancestor_mut<T>(&mut self) -> Option<&mut T> where T: IConstruct + 'static;
+
+    ///
+    /// into_concrete: Attempts to cast this boxed (IConstruct) object into a boxed (Construct)
+    ///                instance, transferring ownership.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The (Construct) type to which this (IConstruct) object will be
+    ///                             cast.
+    ///
+    /// Expresses:
+    /// * If successful, the underlying concrete (Construct) type instance within Ok(Box<T>).
+    /// * Otherwise, the original boxed (IConstruct) object within Err(), so that ownership is
+    ///   never lost.
+    ///
+    fn into_concrete<T>(self: Box<Self>) -> Result<Box<T>, Box<Self>> where T: IConstruct + 'static;
+
+    ///
+    /// into_concrete_rc: Attempts to cast this reference-counted (IConstruct) object into a
+    ///                   reference-counted (Construct) instance, without cloning it.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The (Construct) type to which this (IConstruct) object will be
+    ///                             cast.
+    ///
+    /// Expresses:
+    /// * If successful, the underlying concrete (Construct) type instance within Ok(Rc<T>).
+    /// * Otherwise, the original Rc within Err().
+    ///
+    fn into_concrete_rc<T>(self: Rc<Self>) -> Result<Rc<T>, Rc<Self>>
+        where T: IConstruct + 'static;
+
+    ///
+    /// into_concrete_arc: Attempts to cast this atomically reference-counted (IConstruct) object
+    ///                    into an atomically reference-counted (Construct) instance, without
+    ///                    cloning it.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + 'static): The (Construct) type to which this (IConstruct) object will be
+    ///                             cast.
+    ///
+    /// Expresses:
+    /// * If successful, the underlying concrete (Construct) type instance within Ok(Arc<T>).
+    /// * Otherwise, the original Arc within Err().
+    ///
+    fn into_concrete_arc<T>(self: Arc<Self>) -> Result<Arc<T>, Arc<Self>>
+        where T: IConstruct + 'static;
+}
+
+///
+/// Encompasses: The trait through which a concrete type exposes the (Construct) instance of its
+///              direct parent type that it encompasses.
+///
+/// Generic parameters:
+/// * P: The direct parent (Construct) type.
+///
+/// Notes:
+/// * Implemented by the define_instances! macro for each concrete type and its direct parent.
+/// * Consulted (via Lineage::parent_accessors()) by Necromancer when recovering ancestor
+///   instances.
+///
+pub trait Encompasses<P>
+{
+    ///
+    /// encompassed: Retrieves an immutable reference to the encompassed parent instance.
+    ///
+    fn encompassed(&self) -> &P;
+
+    ///
+    /// encompassed_mut: Retrieves a mutable reference to the encompassed parent instance.
+    ///
+    fn encompassed_mut(&mut self) -> &mut P;
+}
+impl<U> Necromances for U where U: IConstruct + ?Sized
+{
+    fn as_concrete<T>(&self) -> Option<&T> where T: IConstruct + 'static
+    {
+        Necromancer::unearth_concrete::<_, T>(self)
+    }
+    
+    fn as_concrete_mut<T>(&mut self) -> Option<&mut T> where T: IConstruct + 'static
+    {
+        Necromancer::unearth_concrete_mut::<_, T>(self)
+    }
+
+    fn try_as_concrete<T>(&self) -> Result<&T, CastError> where T: IConstruct + 'static
+    {
+        Necromancer::try_unearth_concrete::<_, T>(self)
+    }
+
+    fn try_as_concrete_mut<T>(&mut self) -> Result<&mut T, CastError> where T: IConstruct + 'static
+    {
+        Necromancer::try_unearth_concrete_mut::<_, T>(self)
+    }
+
+    fn as_ancestor<T>(&self) -> Option<&T> where T: IConstruct + 'static
+    {
+        Necromancer::unearth_ancestor::<_, T>(self)
+    }
+
+    fn as_ancestor_mut<T>(&mut self) -> Option<&mut T> where T: IConstruct + 'static
+    {
+        Necromancer::unearth_ancestor_mut::<_, T>(self)
+    }
+
+    fn into_concrete<T>(self: Box<Self>) -> Result<Box<T>, Box<Self>> where T: IConstruct + 'static
+    {
+        Transmuter::transmute_box(self, Necromancer::unearth_concrete_mut::<_, T>)
+    }
+
+    fn into_concrete_rc<T>(self: Rc<Self>) -> Result<Rc<T>, Rc<Self>>
+        where T: IConstruct + 'static
+    {
+        Transmuter::transmute_rc(self, Necromancer::unearth_concrete::<_, T>)
+    }
+
+    fn into_concrete_arc<T>(self: Arc<Self>) -> Result<Arc<T>, Arc<Self>>
+        where T: IConstruct + 'static
+    {
+        Transmuter::transmute_arc(self, Necromancer::unearth_concrete::<_, T>)
+    }
+}
+
+//
+// *** Public structs ***
+//
+
+///
+/// Necromancer: The struct through which casts from (IConstruct) objects to (Construct) instances
+///              may be requested.
+///
+///
+/// Notes:
+/// * Because Rust currently offers no way to specify a constraint that excludes concrete types,
+///   Necromancer functions will accept concrete types as function arguments, even though such
+///   functionality has no real meaning for them.
+///   * While no harm is done if a concrete type is unearthed from itself, doing so is a waste of
+///     CPU cycles.
+///   * In addition, that such a call can be made may be confusing.
+///     * It's important to keep in mind that Necromancer will *not* cast between concrete types,
+///       even though it may appear as though it could.
+///     * Only calls such as vehicle.as_concrete::<Vehicle>() will succeed.
+///     * Other calls, such as vehicle.as_concrete::<Construct>(), will fail, even though Vehicle
+///       encompasses Construct and the corresponding upcast for their interfaces will work through
+///       Transmutation.
+///     * Please see the "Casting scenarios" section within "RDH Inheritance Model.md."
+///
+pub struct Necromancer {}
+impl Necromancer
+{
+    //
+    // Implementation notes:
+    // * While Necromancer and its functions are all public and involve unsafe code, neither the
+    //   struct nor the functions are labelled unsafe because type checking should provide
+    //   sufficient guarantee that the "unsafe" code is, in fact, safe to run.
+    //
+
+    ///
+    /// unearth_concrete: Attempts to cast the specified immutable (IConstruct) object into an
+    ///                   immutable (Construct) instance.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized): The type of immutable (IConstruct) object to cast to an
+    ///                            immutable (Construct) type.
+    /// * U (IConstruct + 'static): The immutable (Construct) type to which the immutable
+    ///                             (IConstruct) object will be cast.
+    ///
+    /// Expresses:
+    /// * If successful, an immutable reference to the underlying concrete (Construct) type
+    ///   instance within an Option<&T>.
+    /// * Otherwise expresses None.
+    ///
+    pub fn unearth_concrete<T, U>(iconstruct: &T) -> Option<&U>
+        where T: IConstruct + ?Sized,
+              U: IConstruct + 'static
     {
-        // Implementation note: It would be better to allow access only to developers extending,
-        //                      rather than consuming, the type hierarchy, but Rust has no way to
-        //                      model that across libraries.
-
-        let type_name = TypeRegistry::get_type_name::<T>();
-        let mut instance = unsafe { TypeRegistry::rwlock() }.write().expect(
-          "TypeRegistry::register_type<T>(): Unable to acquire mutable TypeRegistry singleton \
-           instance!");
-
-        // Make sure the type's name hasn't already been registered....
-        if instance.type_map.contains_key(&type_name) == false
+        // Compare Rust TypeIds directly (rather than via is()), as U is necessarily concrete and
+        // this avoids acquiring the TypeRegistry's lock.
+        if iconstruct.concrete_type_id() == TypeId::of::<U>()
         {
-            // Also make sure the type's UUID hasn't already been registered....
-            if instance.type_map.values().any(|registered_type|
-                                              registered_type.type_uuid == type_uuid)
-            {
-                panic!("TypeRegistry::register_type<T>(): Specified UUID ({}) already registered \
-                        to a different type!", type_uuid);
-            }
-
-            instance.type_map.insert(type_name, TypeIdentifier
-                                                {
-                                                    type_uuid,
-                                                    library_identifier: LibraryIdentifier
-                                                                        {
-                                                                            uuid: library_uuid
-                                                                        }
-                                                });
+            Some(unsafe { &*(iconstruct as *const T as *const U) })
         }
         else
         {
-            panic!("TypeRegistry::register_type<T>(): Indicated type ({}) has already been \
-                    registered!", type_name);
+            None
         }
     }
 
     ///
-    /// type_identifier_of: Retrieves the type identifier assigned to the indicated RDH type.
+    /// unearth_concrete_mut: Attempts to cast the specified mutable (IConstruct) object into a
+    ///                       mutable (Construct) instance.
     ///
     /// Generic parameters:
-    /// * T (IConstruct + ?Sized): The RDH type whose type identifier to retrieve.
-    ///
-    /// Expresses: The type's identifier.
+    /// * T (IConstruct + ?Sized): The type of mutable (IConstruct) object to cast to a mutable
+    ///                            (Construct) type.
+    /// * U (IConstruct + 'static): The mutable (Construct) type to which the mutable
+    ///                             (IConstruct) object will be cast.
     ///
-    /// Panics:
-    /// * If unable to acquire the lock that guards the TypeRegistry singleton instance.
-    /// * If unable to acquire the immutable TypeRegistry singleton instance.
-    /// * If the indicated type has not been registered.
+    /// Expresses:
+    /// * If successful, a mutable reference to the underlying concrete (Construct) type instance
+    ///   within an Option<&mut T>.
+    /// * Otherwise expresses None.
     ///
-    pub fn type_identifier_of<T>() -> TypeIdentifier
-        where T: IConstruct + ?Sized
+    pub fn unearth_concrete_mut<T, U>(iconstruct: &mut T) -> Option<&mut U>
+        where T: IConstruct + ?Sized,
+              U: IConstruct + 'static
     {
-        let type_name = TypeRegistry::get_type_name::<T>();
-        let instance = unsafe { TypeRegistry::rwlock() }.read().expect(
-          "TypeRegistry::type_identifier_of<T>(): Unable to acquire immutable TypeRegistry \
-           singleton instance!");
-        if instance.type_map.contains_key(&type_name) == true
+        // Compare Rust TypeIds directly (rather than via is()), as U is necessarily concrete and
+        // this avoids acquiring the TypeRegistry's lock.
+        if iconstruct.concrete_type_id() == TypeId::of::<U>()
         {
-            instance.type_map[&type_name]
+            Some(unsafe { &mut *(iconstruct as *mut T as *mut U) })
         }
         else
         {
-            panic!("TypeRegistry::type_identifier_of<T>(): Unable to obtain TypeIdentifier for \
-                    {}!", type_name);
+            None
         }
     }
 
     ///
-    /// library_identifier_of: Retrieves the identifier assigned to the library in which the
-    ///                        indicated RDH type is defined.
+    /// try_unearth_concrete: Attempts to cast the specified immutable (IConstruct) object into an
+    ///                       immutable (Construct) instance, describing any failure.
     ///
     /// Generic parameters:
-    /// * T (IConstruct + ?Sized): The RDH type whose library identifier to retrieve.
-    ///
-    /// Expresses: The library's identifier.
+    /// * T (IConstruct + ?Sized): The type of immutable (IConstruct) object to cast to an
+    ///                            immutable (Construct) type.
+    /// * U (IConstruct + 'static): The immutable (Construct) type to which the immutable
+    ///                             (IConstruct) object will be cast.
     ///
-    /// Panics:
-    /// * If unable to acquire the lock that guards the TypeRegistry singleton instance.
-    /// * If unable to acquire the immutable TypeRegistry singleton instance.
-    /// * If the indicated type has not been registered.
+    /// Expresses:
+    /// * If successful, an immutable reference to the underlying concrete (Construct) type
+    ///   instance within Ok().
+    /// * If either type is unregistered, a CastError (with reason CastFailure::UnregisteredType)
+    ///   within Err().
+    /// * Otherwise, a CastError (with reason CastFailure::NotTargetType) within Err().
     ///
-    pub fn library_identifier_of<T>() -> LibraryIdentifier
-        where T: IConstruct + ?Sized
+    pub fn try_unearth_concrete<T, U>(iconstruct: &T) -> Result<&U, CastError>
+        where T: IConstruct + ?Sized,
+              U: IConstruct + 'static
     {
-        TypeRegistry::type_identifier_of::<T>().library_identifier
+        Necromancer::unearth_concrete::<T, U>(iconstruct).ok_or_else(
+          || Necromancer::mismatch_of::<T, U>(iconstruct))
     }
 
-    // *** Private functions ***
-
-    //
-    // get_type_name: Gets the type name for the indicated type.
-    //
-    // Generic types:
-    // * T (IConstruct + ?Sized): The RDH type whose type name to retrieve.
-    //
-    // Expresses:
-    // * The name of the indicated type.
-    //
-    // Panics:
-    // * If unable to acquire the type name.
-    //
-    fn get_type_name<T>() -> String
-        where T: IConstruct + ?Sized
+    ///
+    /// try_unearth_concrete_mut: Attempts to cast the specified mutable (IConstruct) object into a
+    ///                           mutable (Construct) instance, describing any failure.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized): The type of mutable (IConstruct) object to cast to a mutable
+    ///                            (Construct) type.
+    /// * U (IConstruct + 'static): The mutable (Construct) type to which the mutable
+    ///                             (IConstruct) object will be cast.
+    ///
+    /// Expresses:
+    /// * If successful, a mutable reference to the underlying concrete (Construct) type instance
+    ///   within Ok().
+    /// * If either type is unregistered, a CastError (with reason CastFailure::UnregisteredType)
+    ///   within Err().
+    /// * Otherwise, a CastError (with reason CastFailure::NotTargetType) within Err().
+    ///
+    pub fn try_unearth_concrete_mut<T, U>(iconstruct: &mut T) -> Result<&mut U, CastError>
+        where T: IConstruct + ?Sized,
+              U: IConstruct + 'static
     {
-        //
-        // Implementation notes:
-        //
-        // * To understand why this method is needed and the ways in which it's currently limited,
-        //   see Issue #8 and Issue #9 in "Knowledge Base.md."
-        //
-        // * Because all casting operations call this method at least once, it must be as
-        //   performant as possible.
-        //   * While str offers search functionality and there is a regex crate, manually parsing
-        //     the string one time will likely be more performant than employing multiple str
-        //     operations and doesn't require the use of an additional crate, something that
-        //     conflicts with the initial design goals of RDH.
-        //
-
-        // ASSUMPTION: At this time, std::any::type_name<T>() is expected to yield strings with
-        //             interspersed legal segments only on the *left* side of the intended payload.
-        //             * To optimize performance, we assume this pattern will hold.
-        //             * If a legal string segment were found to the right of the intended payload,
-        //               the unwanted legal string would be extracted instead.
-        //             * If such a string were encounterd, we would need to issue an update pronto.
-
-        // Get the type's name per Rust.
-        let rust_type_name = any::type_name::<T>();
-        let rust_name_length = rust_type_name.chars().count();
-        if rust_name_length == 0
+        // Test the TypeIds here (rather than via unearth_concrete_mut()), as the mutable borrow
+        // couldn't otherwise be released to describe a failure.
+        if iconstruct.concrete_type_id() != TypeId::of::<U>()
         {
-            panic!("TypeRegistry::get_type_name<T>(): Unable to acquire the type name for an \
-                   (IConstruct)!");
+            return Err(Necromancer::mismatch_of::<T, U>(iconstruct));
         }
+        Ok(unsafe { &mut *(iconstruct as *mut T as *mut U) })
+    }
 
-        // Find the index of the first invalid symbol after the last valid symbol.
-        let mut index = rust_name_length - 1;
-        let mut slice_end = 0;
-        for char in rust_type_name.chars().rev()
+    ///
+    /// unearth_ancestor: Attempts to recover the immutable (Construct) instance of the indicated
+    ///                   type that is encompassed, directly or indirectly, by the specified
+    ///                   immutable (IConstruct) object's underlying concrete instance.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized): The type of immutable (IConstruct) object from which to recover
+    ///                            the ancestor instance.
+    /// * U (IConstruct + 'static): The ancestor (Construct) type to recover.
+    ///
+    /// Expresses:
+    /// * If successful, an immutable reference to the encompassed U instance within an
+    ///   Option<&U>.
+    /// * Otherwise expresses None.
+    ///
+    /// Notes:
+    /// * The ancestor instance is reached by following the chain of parent accessors recorded for
+    ///   each concrete type when it was registered (see Encompasses).
+    ///
+    pub fn unearth_ancestor<T, U>(iconstruct: &T) -> Option<&U>
+        where T: IConstruct + ?Sized,
+              U: IConstruct + 'static
+    {
+        let accessor_path =
+          TypeRegistry::try_accessor_path(iconstruct.try_concrete_type_identifier().ok()?,
+                                          TypeRegistry::try_type_identifier_of::<U>().ok()?)
+                       .ok()??;
+        let mut pointer = iconstruct as *const T as *const ();
+        for parent_accessors in accessor_path
         {
-            if (char >= 'A' && char <= 'Z') || (char >= 'a' && char <= 'z') ||
-               (char >= '0' && char <= '9') || char == '_'
-            {
-                if slice_end == 0
-                {
-                    slice_end = rust_name_length;
-                }
-                break;
-            }
-
-            slice_end = index;
-
-            if index == 0
-            {
-                panic!("TypeRegistry::get_type_name<T>(): No legal characters found within \
-                        the type name for the specified (IConstruct) as reported by Rust! \
-                        (\"{}\")", rust_type_name);
-            }
-            index -= 1;
+            pointer = unsafe { (parent_accessors.get)(pointer) };
         }
+        Some(unsafe { &*(pointer as *const U) })
+    }
 
-        // Find the index of the first valid symbol after the last invalid symbol prior to those
-        // found above.
-        index = 0;
-        let mut slice_start = 0;
-        for char in rust_type_name.chars()
+    ///
+    /// unearth_ancestor_mut: Attempts to recover the mutable (Construct) instance of the indicated
+    ///                       type that is encompassed, directly or indirectly, by the specified
+    ///                       mutable (IConstruct) object's underlying concrete instance.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized): The type of mutable (IConstruct) object from which to recover
+    ///                            the ancestor instance.
+    /// * U (IConstruct + 'static): The ancestor (Construct) type to recover.
+    ///
+    /// Expresses:
+    /// * If successful, a mutable reference to the encompassed U instance within an
+    ///   Option<&mut U>.
+    /// * Otherwise expresses None.
+    ///
+    pub fn unearth_ancestor_mut<T, U>(iconstruct: &mut T) -> Option<&mut U>
+        where T: IConstruct + ?Sized,
+              U: IConstruct + 'static
+    {
+        let accessor_path =
+          TypeRegistry::try_accessor_path(iconstruct.try_concrete_type_identifier().ok()?,
+                                          TypeRegistry::try_type_identifier_of::<U>().ok()?)
+                       .ok()??;
+        let mut pointer = iconstruct as *mut T as *mut ();
+        for parent_accessors in accessor_path
         {
-            if (char < 'A' || char > 'Z') && (char < 'a' || char > 'z') &&
-               (char < '0' || char > '9') && char != '_' && char != ':'
-            {
-                slice_start = index + 1;
-            }
-            index += 1;
-
-            if index == slice_end
-            {
-                break;
-            }
+            pointer = unsafe { (parent_accessors.get_mut)(pointer) };
         }
-
-        // Extract the type's name.
-        rust_type_name[slice_start .. slice_end].to_string()
+        Some(unsafe { &mut *(pointer as *mut U) })
     }
 
+    // *** Private functions ***
+
     //
-    // rwlock: Obtains a reference to the reader/writer lock that guards access to the singleton
-    //         TypeRegistry instance (creating both when necessary).
+    // mismatch_of: Creates the CastError describing why the specified (IConstruct) object couldn't
+    //              be cast to the indicated concrete type.
     //
-    // Expresses: A reference to the lock that guards the singleton instance.
+    // Generic parameters:
+    // * T (IConstruct + ?Sized): The type of (IConstruct) object that couldn't be cast.
+    // * U (IConstruct + 'static): The (Construct) type to which it couldn't be cast.
+    //
+    // Parameters:
+    // * iconstruct (&T): The (IConstruct) object that couldn't be cast.
     //
-    // Panics:
-    // * If unable to acquire the lock that guards the TypeRegistry singleton instance.
+    // Expresses: The CastError, with reason CastFailure::NotTargetType unless either type is
+    //            unregistered.
     //
-    unsafe fn rwlock<'a>() -> &'a RwLock<TypeRegistry>
+    fn mismatch_of<T, U>(iconstruct: &T) -> CastError
+        where T: IConstruct + ?Sized,
+              U: IConstruct + 'static
     {
-        // Implementation notes: Structs may not contain static fields, so we have to declare the
-        // singleton instance and the rwlock that guards it here (which has its advantages).
-
-        // The RwLock, embedded within an Option, that guards the singleton instance of the
-        // TypeRegistry struct.
-        static mut RWLOCK: Option<RwLock<TypeRegistry>> = None;
-
-        // Create the rwlock and singleton instance the first time this function is called (while
-        // all other calling threads are blocked).
-        static ONCE: Once = Once::new();
-        ONCE.call_once(||
-                       {
-                           RWLOCK = Some(RwLock::new(TypeRegistry { type_map: HashMap::new() }));
-                       });
-
-        RWLOCK.as_ref().expect("TypeRegistry::rwlock(): Unable to acquire the lock that guards \
-                                the TypeRegistry singleton instance!")
+        CastError::from_lookups(iconstruct.try_concrete_type_identifier(),
+                                TypeRegistry::try_type_identifier_of::<U>(),
+                                |_, _| CastFailure::NotTargetType)
     }
 }
 
 ///
-/// TypeIdentifier: Represents an RDH type's identifier.
+/// ParentAccessors: The pair of type-erased functions through which a concrete type's encompassed
+///                  direct parent instance may be reached.
 ///
 /// Notes:
-/// * TypeIdentifier instances should be treated as opaque blobs, requested only when needed and passed on blindly.
-//  * That UUIDs can accomodate up to 18.5 quintillion values is not meant to recommend this as a
-//    reasonable number of hirarchy types for any application! :)
-//    * Instead, using u128s for identifiers is intended to ease parallel development and
-//      integration work across large teams with multiple dependencies.
-//    * Just as with UUIDs in other languages, randomly selected u128 values are exceedingly
-//      unlikely to conflict.
+/// * Created by the provision_transmutation! macro (via Lineage::parent_accessors()) and recorded
+///   by the TypeRegistry when the concrete type is registered.
 ///
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct TypeIdentifier
+#[derive(Clone, Copy)]
+pub struct ParentAccessors
 {
-    // The unique identifier for the RDH type.
-    type_uuid: u128,
+    // Given a pointer to a concrete instance, expresses a pointer to its parent instance.
+    get: unsafe fn(*const ()) -> *const (),
 
-    // The unique identifier for the library in which the RDH type is defined.
-    library_identifier: LibraryIdentifier
+    // Given a mutable pointer to a concrete instance, expresses a mutable pointer to its parent
+    // instance.
+    get_mut: unsafe fn(*mut ()) -> *mut ()
 }
-
-///
-/// LibraryIdentifier: The unique identifier for an RDH library.
-///
-/// Notes:
-/// * LibraryIdentifier instances should be treated as opaque blobs, requested only when needed and passed on blindly.
-//  * That UUIDs can accomodate up to 18.5 quintillion values is not meant to recommend this as a
-//    reasonable number of RDH libraries for any application! :)
-//    * Instead, using u128s for identifiers is intended to ease parallel development and
-//      integration work across large teams with multiple dependencies.
-//    * Just as with UUIDs in other languages, randomly selected u128 values are exceedingly
-//      unlikely to conflict.
-///
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub struct LibraryIdentifier
+impl ParentAccessors
 {
-    uuid: u128
+    // *** Public functions ***
+
+    ///
+    /// of: Creates the parent accessors for the indicated concrete type and its direct parent.
+    ///
+    /// Generic parameters:
+    /// * T (Encompasses<P>): The concrete type.
+    /// * P: The concrete type's direct parent type.
+    ///
+    /// Expresses: The new ParentAccessors instance.
+    ///
+    pub fn of<T, P>() -> ParentAccessors where T: Encompasses<P>
+    {
+        ParentAccessors
+        {
+            get: ParentAccessors::parent_of::<T, P>,
+            get_mut: ParentAccessors::parent_of_mut::<T, P>
+        }
+    }
+
+    // *** Private functions ***
+
+    //
+    // parent_of: Expresses a pointer to the parent instance encompassed by the indicated instance.
+    //
+    // Safety: The specified pointer must point to a valid T instance.
+    //
+    unsafe fn parent_of<T, P>(this: *const ()) -> *const () where T: Encompasses<P>
+    {
+        (*(this as *const T)).encompassed() as *const P as *const ()
+    }
+
+    //
+    // parent_of_mut: Expresses a mutable pointer to the parent instance encompassed by the
+    //                indicated instance.
+    //
+    // Safety: The specified pointer must point to a valid T instance.
+    //
+    unsafe fn parent_of_mut<T, P>(this: *mut ()) -> *mut () where T: Encompasses<P>
+    {
+        (*(this as *mut T)).encompassed_mut() as *mut P as *mut ()
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
-// Necromancy: The power to raise the dead.
+// Transmutation: The power to transform.
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 //
@@ -495,165 +4013,304 @@ pub struct LibraryIdentifier
 //
 
 ///
-/// Necromances: The trait through which casts from (IConstruct) objects to (Construct) instances
-///              may be requested.
+/// Transmutes: The trait through which downcasts from (IConstruct) objects to any downcastable
+///             public interface may be requested generically.
 ///
 /// Notes:
-/// * Because Rust currently offers no way to specify a constraint that excludes concrete types,
-///   the Necromances trait is implemented for concrete types even though such functionality has no
-///   real meaning for them.
-///   * While no harm is done if a concrete type is cast to itself in this way, doing so is a waste
-///     of CPU cycles.
-///   * In addition, that such a call can be made may be confusing.
-///     * It's important to keep in mind that Necromancer will *not* cast between concrete types,
-///       even though it may appear as though it could.
-///     * Only calls such as vehicle.as_concrete::<Vehicle>() will succeed.
-///     * Other calls, such as vehicle.as_concrete::<Construct>(), will fail, even though Vehicle
-///       encompasses Construct and the corresponding upcast for their interfaces will work through
-///       Transmutation.
-///     * Please see the "Casting scenarios" section within "RDH Inheritance Model.md."
+/// * Offers a single entry point in place of each interface's named methods, e.g.
+///   iconstruct.cast::<dyn ICar>() rather than iconstruct.as_icar(), allowing generic code to be
+///   written over "any target interface."
+/// * Targets are those public interfaces that implement CastTarget (i.e. those provisioned with
+///   downcasting support by provision_transmutation!).
 ///
-pub trait Necromances : IConstruct
+pub trait Transmutes : IConstruct
 {
     ///
-    /// as_concrete: Attempts to cast this immutable (IConstruct) object into an immutable
-    ///              (Construct) instance.
+    /// cast: Attempts to downcast this immutable (IConstruct) object to the indicated immutable
+    ///       public interface.
     ///
     /// Generic parameters:
-    /// * T (IConstruct): The immutable (Construct) type to which this immutable (IConstruct)
-    ///                   object will be cast.
+    /// * U (CastTarget + ?Sized): The public interface (e.g. dyn ICar) to which this object will be
+    ///                            cast.
     ///
     /// Expresses:
-    /// * If successful, an immutable reference to the underlying concrete (Construct) type
-    ///   instance within an Option<&T>.
+    /// * If successful, the immutable interface object within Some().
     /// * Otherwise expresses None.
     ///
-    fn as_concrete<T>(&self) -> Option<&T> where T: IConstruct;
+    fn cast<U>(&self) -> Option<&U> where U: CastTarget + ?Sized;
 
     ///
-    /// as_concrete_mut: Attempts to cast this mutable (IConstruct) object into a mutable
-    ///                  (Construct) instance.
+    /// cast_mut: Attempts to downcast this mutable (IConstruct) object to the indicated mutable
+    ///           public interface.
     ///
     /// Generic parameters:
-    /// * T (IConstruct): The mutable (Construct) type to which this mutable (IConstruct) object
-    ///                   will be cast.
+    /// * U (CastTarget + ?Sized): The public interface (e.g. dyn ICar) to which this object will be
+    ///                            cast.
     ///
     /// Expresses:
-    /// * If successful, a mutable reference to the underlying concrete (Construct) type instance
-    ///   within an Option<&mut T>.
+    /// * If successful, the mutable interface object within Some().
     /// * Otherwise expresses None.
     ///
-    fn as_concrete_mut<T>(&mut self) -> Option<&mut T> where T: IConstruct;
+    fn cast_mut<U>(&mut self) -> Option<&mut U> where U: CastTarget + ?Sized;
+
+    ///
+    /// try_cast: Attempts to downcast this immutable (IConstruct) object to the indicated immutable
+    ///           public interface, describing any failure.
+    ///
+    /// Generic parameters:
+    /// * U (CastTarget + ?Sized): The public interface (e.g. dyn ICar) to which this object will be
+    ///                            cast.
+    ///
+    /// Expresses:
+    /// * If successful, the immutable interface object within Ok().
+    /// * Otherwise, a CastError describing the failure within Err().
+    ///
+    fn try_cast<U>(&self) -> Result<&U, CastError> where U: CastTarget + ?Sized;
+
+    ///
+    /// try_cast_mut: Attempts to downcast this mutable (IConstruct) object to the indicated
+    ///               mutable public interface, describing any failure.
+    ///
+    /// Generic parameters:
+    /// * U (CastTarget + ?Sized): The public interface (e.g. dyn ICar) to which this object will be
+    ///                            cast.
+    ///
+    /// Expresses:
+    /// * If successful, the mutable interface object within Ok().
+    /// * Otherwise, a CastError describing the failure within Err().
+    ///
+    fn try_cast_mut<U>(&mut self) -> Result<&mut U, CastError> where U: CastTarget + ?Sized;
 }
-impl<U> Necromances for U where U: IConstruct + ?Sized
+impl<T> Transmutes for T where T: IConstruct + ?Sized
 {
-    fn as_concrete<T>(&self) -> Option<&T> where T: IConstruct
+    fn cast<U>(&self) -> Option<&U> where U: CastTarget + ?Sized
     {
-        Necromancer::unearth_concrete::<_, T>(self)
+        U::cast_from(self)
     }
-    
-    fn as_concrete_mut<T>(&mut self) -> Option<&mut T> where T: IConstruct
+
+    fn cast_mut<U>(&mut self) -> Option<&mut U> where U: CastTarget + ?Sized
     {
-        Necromancer::unearth_concrete_mut::<_, T>(self)
+        U::cast_from_mut(self)
     }
-}
 
-//
-// *** Public structs ***
-//
+    fn try_cast<U>(&self) -> Result<&U, CastError> where U: CastTarget + ?Sized
+    {
+        U::try_cast_from(self)
+    }
+
+    fn try_cast_mut<U>(&mut self) -> Result<&mut U, CastError> where U: CastTarget + ?Sized
+    {
+        U::try_cast_from_mut(self)
+    }
+}
 
 ///
-/// Necromancer: The struct through which casts from (IConstruct) objects to (Construct) instances
-///              may be requested.
-///
+/// CastTarget: The trait that marks a public interface's trait object type (e.g. dyn ICar) as a
+///             valid target for the generic casts of the Transmutes trait.
 ///
 /// Notes:
-/// * Because Rust currently offers no way to specify a constraint that excludes concrete types,
-///   Necromancer functions will accept concrete types as function arguments, even though such
-///   functionality has no real meaning for them.
-///   * While no harm is done if a concrete type is unearthed from itself, doing so is a waste of
-///     CPU cycles.
-///   * In addition, that such a call can be made may be confusing.
-///     * It's important to keep in mind that Necromancer will *not* cast between concrete types,
-///       even though it may appear as though it could.
-///     * Only calls such as vehicle.as_concrete::<Vehicle>() will succeed.
-///     * Other calls, such as vehicle.as_concrete::<Construct>(), will fail, even though Vehicle
-///       encompasses Construct and the corresponding upcast for their interfaces will work through
-///       Transmutation.
-///     * Please see the "Casting scenarios" section within "RDH Inheritance Model.md."
+/// * Implemented for each downcastable public interface by the provision_transmutation! macro,
+///   routing each cast through the interface's downcasting struct.
+///
+pub trait CastTarget
+{
+    ///
+    /// cast_from: Attempts to downcast the specified immutable (IConstruct) object to this public
+    ///            interface.
+    ///
+    fn cast_from<T>(iconstruct: &T) -> Option<&Self> where T: IConstruct + ?Sized;
+
+    ///
+    /// cast_from_mut: Attempts to downcast the specified mutable (IConstruct) object to this
+    ///                public interface.
+    ///
+    fn cast_from_mut<T>(iconstruct: &mut T) -> Option<&mut Self> where T: IConstruct + ?Sized;
+
+    ///
+    /// try_cast_from: Attempts to downcast the specified immutable (IConstruct) object to this
+    ///                public interface, describing any failure.
+    ///
+    fn try_cast_from<T>(iconstruct: &T) -> Result<&Self, CastError>
+        where T: IConstruct + ?Sized;
+
+    ///
+    /// try_cast_from_mut: Attempts to downcast the specified mutable (IConstruct) object to this
+    ///                    public interface, describing any failure.
+    ///
+    fn try_cast_from_mut<T>(iconstruct: &mut T) -> Result<&mut Self, CastError>
+        where T: IConstruct + ?Sized;
+}
+
+//
+// *** Public structs ***
+//
+
 ///
-pub struct Necromancer {}
-impl Necromancer
+/// Transmuter: The struct through which borrowed casts (upcasts, downcasts, and concrete type
+///             recovery alike) may be applied to owned Box, Rc, and Arc pointers, transferring
+///             ownership rather than cloning.
+///
+/// Notes:
+/// * Each function accepts the owned pointer and the borrowed cast to apply to it, e.g.:
+///
+/// ```
+///    let icar: Rc<dyn ICar> = Transmuter::transmute_rc(iconstruct, ICarDowncaster::as_icar)
+///                               .expect("Not a Car!");
+/// ```
+///
+/// * The downcasting structs created by provision_transmutation! also expose this functionality
+///   directly, e.g. ICarDowncaster::downcast_rc(iconstruct).
+/// * Owned *upcasts* are also supported natively by Rust's trait upcasting coercion, e.g.
+///   `let iconstruct: Rc<dyn IConstruct> = icar;`
+///
+pub struct Transmuter {}
+impl Transmuter
 {
     //
     // Implementation notes:
-    // * While Necromancer and its functions are all public and involve unsafe code, neither the
-    //   struct nor the functions are labelled unsafe because type checking should provide
-    //   sufficient guarantee that the "unsafe" code is, in fact, safe to run.
+    // * Ownership may only be transferred when the borrowed cast expresses the very same concrete
+    //   instance that the source pointer owns (i.e. the same address, concrete type, size and
+    //   alignment).
+    //   * This rules out, for example, reinterpreting a Box<Checkbox> as a Box<Construct> merely
+    //     because the Construct instance happens to share the Checkbox instance's address.
+    // * When these conditions are met, the target pointer describes the source allocation exactly,
+    //   so handing it back to Box, Rc, or Arc is sound; thus these functions aren't labelled
+    //   unsafe.
     //
 
+    // *** Public functions ***
+
     ///
-    /// unearth_concrete: Attempts to cast the specified immutable (IConstruct) object into an
-    ///                   immutable (Construct) instance.
+    /// transmute_box: Attempts to apply the specified mutable cast to the specified boxed
+    ///                (IConstruct), transferring ownership to the result.
     ///
     /// Generic parameters:
-    /// * T (IConstruct + ?Sized): The type of immutable (IConstruct) object to cast to an
-    ///                            immutable (Construct) type.
-    /// * U (IConstruct): The immutable (Construct) type to which the immutable (IConstruct) object
-    ///                   will be cast.
+    /// * T (IConstruct + ?Sized): The type of the source (IConstruct).
+    /// * U (IConstruct + ?Sized): The type of the target (IConstruct).
+    ///
+    /// Parameters:
+    /// * source (Box<T>): The boxed (IConstruct) to cast.
+    /// * cast (impl FnOnce(&mut T) -> Option<&mut U>): The mutable cast to apply.
     ///
     /// Expresses:
-    /// * If successful, an immutable reference to the underlying concrete (Construct) type
-    ///   instance within an Option<&T>.
-    /// * Otherwise expresses None.
+    /// * If successful, the same instance as a Box<U> within Ok().
+    /// * Otherwise, the source Box within Err().
     ///
-    pub fn unearth_concrete<T, U>(iconstruct: &T) -> Option<&U>
+    pub fn transmute_box<T, U>(mut source: Box<T>, cast: impl FnOnce(&mut T) -> Option<&mut U>) ->
+      Result<Box<U>, Box<T>>
         where T: IConstruct + ?Sized,
-              U: IConstruct
+              U: IConstruct + ?Sized
     {
-        if iconstruct.is(TypeRegistry::type_identifier_of::<U>())
+        let source_identity = Transmuter::identity_of(&*source);
+        let target = match cast(&mut source)
         {
-            Some(unsafe { &*(iconstruct as *const T as *const U) })
-        }
-        else
+            Some(target) => target as *mut U,
+            None => return Err(source)
+        };
+        if Transmuter::identity_of(unsafe { &*target }) != source_identity
         {
-            None
+            return Err(source);
         }
+        let _ = Box::into_raw(source);
+        Ok(unsafe { Box::from_raw(target) })
     }
 
     ///
-    /// unearth_concrete_mut: Attempts to cast the specified mutable (IConstruct) object into a
-    ///                       mutable (Construct) instance.
+    /// transmute_rc: Attempts to apply the specified immutable cast to the specified
+    ///               reference-counted (IConstruct), transferring ownership to the result.
     ///
     /// Generic parameters:
-    /// * T (IConstruct + ?Sized): The type of mutable (IConstruct) object to cast to a mutable
-    ///                            (Construct) type.
-    /// * U (IConstruct): The mutable (Construct) type to which the mutable (IConstruct) object
-    ///                   will be cast.
+    /// * T (IConstruct + ?Sized): The type of the source (IConstruct).
+    /// * U (IConstruct + ?Sized): The type of the target (IConstruct).
+    ///
+    /// Parameters:
+    /// * source (Rc<T>): The reference-counted (IConstruct) to cast.
+    /// * cast (impl FnOnce(&T) -> Option<&U>): The immutable cast to apply.
     ///
     /// Expresses:
-    /// * If successful, a mutable reference to the underlying concrete (Construct) type instance
-    ///   within an Option<&mut T>.
-    /// * Otherwise expresses None.
+    /// * If successful, the same instance as an Rc<U> (sharing the source's reference count)
+    ///   within Ok().
+    /// * Otherwise, the source Rc within Err().
     ///
-    pub fn unearth_concrete_mut<T, U>(iconstruct: &mut T) -> Option<&mut U>
+    pub fn transmute_rc<T, U>(source: Rc<T>, cast: impl FnOnce(&T) -> Option<&U>) ->
+      Result<Rc<U>, Rc<T>>
         where T: IConstruct + ?Sized,
-              U: IConstruct
+              U: IConstruct + ?Sized
     {
-        if iconstruct.is(TypeRegistry::type_identifier_of::<U>())
+        let source_identity = Transmuter::identity_of(&*source);
+        let target = match cast(&source)
         {
-            Some(unsafe { &mut *(iconstruct as *mut T as *mut U) })
+            Some(target) => target as *const U,
+            None => return Err(source)
+        };
+        if Transmuter::identity_of(unsafe { &*target }) != source_identity
+        {
+            return Err(source);
         }
-        else
+        let _ = Rc::into_raw(source);
+        Ok(unsafe { Rc::from_raw(target) })
+    }
+
+    ///
+    /// transmute_arc: Attempts to apply the specified immutable cast to the specified atomically
+    ///                reference-counted (IConstruct), transferring ownership to the result.
+    ///
+    /// Generic parameters:
+    /// * T (IConstruct + ?Sized): The type of the source (IConstruct).
+    /// * U (IConstruct + ?Sized): The type of the target (IConstruct).
+    ///
+    /// Parameters:
+    /// * source (Arc<T>): The atomically reference-counted (IConstruct) to cast.
+    /// * cast (impl FnOnce(&T) -> Option<&U>): The immutable cast to apply.
+    ///
+    /// Expresses:
+    /// * If successful, the same instance as an Arc<U> (sharing the source's reference count)
+    ///   within Ok().
+    /// * Otherwise, the source Arc within Err().
+    ///
+    pub fn transmute_arc<T, U>(source: Arc<T>, cast: impl FnOnce(&T) -> Option<&U>) ->
+      Result<Arc<U>, Arc<T>>
+        where T: IConstruct + ?Sized,
+              U: IConstruct + ?Sized
+    {
+        let source_identity = Transmuter::identity_of(&*source);
+        let target = match cast(&source)
         {
-            None
+            Some(target) => target as *const U,
+            None => return Err(source)
+        };
+        if Transmuter::identity_of(unsafe { &*target }) != source_identity
+        {
+            return Err(source);
         }
+        let _ = Arc::into_raw(source);
+        Ok(unsafe { Arc::from_raw(target) })
     }
-}
 
-///////////////////////////////////////////////////////////////////////////////////////////////////
-// Transmutation: The power to transform.
-///////////////////////////////////////////////////////////////////////////////////////////////////
+    // *** Private functions ***
+
+    //
+    // identity_of: Retrieves the properties that together identify the concrete instance
+    //              underlying the specified (IConstruct).
+    //
+    // Parameters:
+    // * iconstruct (&T): The (IConstruct) whose underlying concrete instance to identify.
+    //
+    // Expresses: The instance's address, concrete Rust TypeId, size, and alignment.
+    //
+    // Notes:
+    // * The TypeId (rather than the TypeIdentifier) is compared so that identifying an instance
+    //   never acquires the TypeRegistry's lock, nor panics when its type is unregistered.
+    //
+    fn identity_of<T>(iconstruct: &T) -> (*const (), TypeId, usize, usize)
+        where T: IConstruct + ?Sized
+    {
+        (iconstruct as *const T as *const (),
+         iconstruct.concrete_type_id(),
+         mem::size_of_val(iconstruct),
+         mem::align_of_val(iconstruct))
+    }
+}
 
 //
 // *** Public macros ***
@@ -699,11 +4356,19 @@ impl Necromancer
 ///   * $library_concrete_type (ident): The space-delimited list of downstream concrete types
 ///                                     within the current RDH library in addition to
 ///                                     $concrete_type whose (IConstruct) objects may be downcast
-///                                     to $target_interface, if any.
+///                                     to $target_interface, if any. Downstream types needn't be
+///                                     listed, as each is registered with
+///                                     $global_downcasting_struct automatically (see
+///                                     DerivedCasts).
+///   * $parent_type (ident): The concrete type directly encompassed by $concrete_type, recorded
+///                           within the TypeRegistry when $concrete_type is registered. Although
+///                           optional, it should be specified for every type other than the
+///                           hierarchy root (also available for generic types, as a ty).
 ///
 /// Runtime panics:
 /// * If a $library_downcasting_trait handler for the specified library has already been registered
-///   within $global_downcasting_struct.
+///   within $global_downcasting_struct via register_handler().
+///   * try_register_handler() instead expresses a RegistryError in this case.
 /// * If unable to acquire the mutable $global_downcasting_struct singleton instance.
 /// * If unable to acquire the immutable $global_downcasting_struct singleton instance.
 /// * If unable to acquire the lock that guards the $global_downcasting_struct singleton instance.
@@ -723,12 +4388,25 @@ impl Necromancer
 ///      }
 /// ```
 ///
+///  To provision upcasting support for a generic type (downcasting to interfaces introduced by
+///  generic types is not currently supported):
+///
+/// ```
+///      provision_transmutation!
+///      {
+///          for<T> Property<T>: IProperty + [IConstruct] extends Construct
+///          {
+///              add Fn[as_iproperty, as_iproperty_mut] to UpcastsToIProperty
+///          }
+///      }
+/// ```
+///
 ///  To provision both upcasting and downcasting support:
 ///
 /// ```
 ///      provision_transmutation!
 ///      {
-///          for Car: ICar + [IVehicle IConstruct]
+///          for Car: ICar + [IVehicle IConstruct] extends Vehicle
 ///          {
 ///              add Fn[as_icar, as_icar_mut] to
 ///              (
@@ -743,48 +4421,86 @@ impl Necromancer
 ///
 /// Notes:
 ///
+/// * Each non-generic $concrete_type and $target_interface (and, for downcasting support,
+///   $library_downcasting_struct) must be listed within its module's register_types! invocation;
+///   otherwise, compilation fails (see Registered). Generic types are exempt, but their families
+///   and members should be listed there as well.
+///
 /// * For downcasting support:
 ///
 ///   * While invoking this macro for each RDH type will wire all casting support that must exist
 ///     at compile time, some runtime wiring is required as well:
-///     * Each library must register new RDH types and their downcast handlers via a pub init()
-///       function and constant UUIDs.
-///     * For example, within lib.rs for a hypothetical RDH extension library:
+///     * Each module must register its RDH types and downcast handlers via register_types! and
+///       constant UUIDs, and each library must list that module within provision_library!.
+///     * For example, within car.rs for a hypothetical RDH extension library:
 ///
 /// ```
 ///    pub(crate) const ICAR_TYPE_UUID: u128 = 997210989131515082813199923745508083862;
 ///    pub(crate) const CAR_TYPE_UUID: u128 = 161761007873234949512912432941437765752;
 ///     ...
-///    const RDH_EXTENSION_UUID: u128 = 13776724624830763780350194868997388084;
-///    pub fn init()
+///    register_types!
 ///    {
-///        vehicle_library::init();  // This in turn will invoke rdh::init().
-///        TypeRegistry::register_type::<dyn ICar>(ICAR_TYPE_UUID, RDH_EXTENSION_UUID);
-///        TypeRegistry::register_type::<Car>(CAR_TYPE_UUID, RDH_EXTENSION_UUID);
-///        ICarDowncaster::register_handler(TypeRegistry::library_identifier_of::<Car>(),
-///                                         Box::new(DowncastsRdhExtensionTypesToICar{}));
+///        types [dyn ICar = ICAR_TYPE_UUID, Car = CAR_TYPE_UUID]
+///        handlers [ICarDowncaster: DowncastsRdhExtensionTypesToICar]
 ///    }
-///
 /// ```
 ///
-///   * When subsequent RDH libraries add new types downstream to $concrete_type, the
-///     extend_downcasting! macro must be invoked and extensions to previous downcast handlers must
-///     be added to init().
-///     * For example, within lib.rs for an additional hypothetical RDH extension library:
+///   * When new types are added downstream to $concrete_type, each is registered with
+///     $global_downcasting_struct as its module's register_types! registers it, as each listed
+///     $upstream_interface (other than IConstruct) names an interface to which it must be
+///     downcastable (see DerivedCasts).
+///   * Alternatively, when subsequent RDH libraries add new types downstream to $concrete_type,
+///     the extend_downcasting! macro may be invoked and its handler listed within
+///     register_types!.
+///     * For example, within sedan.rs for an additional hypothetical RDH extension library:
 ///
 /// ```
-///    pub fn init()
+///    register_types!
 ///    {
-///        // <library initialization as shown above>
 ///         ...
-///        ICarDowncaster::register_handler(TypeRegistry::library_identifier_of::<FullSizeSedan>(),
-///                                         Box::new(DowncastsSecondExtensionTypesToICar{}));
+///        handlers [ICarDowncaster: DowncastsSecondExtensionTypesToICar]
 ///    }
 /// ```
 ///
 #[macro_export]
 macro_rules! provision_transmutation
 {
+    // *** Derived type registration (nothing is downcast to IConstruct, the hierarchy root)... ***
+    (@register_derived $concrete_type: ident, IConstruct) => {};
+
+    (@register_derived $concrete_type: ident, $upstream_interface: ident) =>
+    {
+        // Types already registered (e.g. within a handler's include [...] list) are left as is.
+        match <<dyn $upstream_interface as Downcastable>::Downcaster>::try_register_derived_type::<
+                $concrete_type>()
+        {
+            Ok(()) | Err(RegistryError::DuplicateDerivedType { .. }) => {},
+            Err(error) => panic!("{}::register_derived_casts(): {}", stringify!($concrete_type),
+                                 error)
+        }
+    };
+
+    // *** Generic types (upcasting-only)... ***
+    {
+        for<$($generic: ident),+> $concrete_type: ty: $target_interface: ident
+                                                      $( + [$($upstream_interface: ident)+] )?
+                                                      $( extends $parent_type: ty )?
+        {
+            add Fn[$cast: ident, $cast_mut: ident] to $upcast_trait: ident
+        }
+    } =>
+    {
+        // Implement the ConcreteDivinator and Lineage traits for all instantiations of
+        // $concrete_type.
+        impl_concrete_divinator!(<$($generic),+> $concrete_type, $target_interface
+                                                 $( $( , $upstream_interface )+ )?
+                                                 $( ; extends $parent_type )?);
+        impl DerivedCasts for dyn $target_interface {}
+
+        // Provision upcasting support for the public interfaces of all encompassing types.
+        provision_upcasting!($upcast_trait, $target_interface, $cast, $cast_mut);
+    };
+
     // *** Upcasting-only... ***
     {
         for $concrete_type: ident: $target_interface: ident
@@ -793,8 +4509,15 @@ macro_rules! provision_transmutation
         }
     } =>
     {
-        // Implement the ConcreteDivinator trait for $concrete_type.
+        // Implement the ConcreteDivinator and Lineage traits for $concrete_type.
         impl_concrete_divinator!($concrete_type, $target_interface);
+        impl DerivedCasts for $concrete_type {}
+        impl DerivedCasts for dyn $target_interface {}
+
+        // Fail to compile unless $concrete_type and $target_interface are registered via
+        // register_types!.
+        const _: () = <$concrete_type as Registered>::REGISTERED;
+        const _: () = <dyn $target_interface as Registered>::REGISTERED;
 
         // Provision upcasting support for the public interfaces of all encompassing types.
         provision_upcasting!($upcast_trait, $target_interface, $cast, $cast_mut);
@@ -803,6 +4526,7 @@ macro_rules! provision_transmutation
     // *** The whole #!... ***
     {
         for $concrete_type: ident: $target_interface: ident + [$( $upstream_interface: ident )+]
+                                   $( extends $parent_type: ident )?
         {
             add Fn[$cast: ident, $cast_mut: ident] to
             (
@@ -815,8 +4539,31 @@ macro_rules! provision_transmutation
         }
     } =>
     {
-        // Implement the ConcreteDivinator trait for $concrete_type.
-        impl_concrete_divinator!($concrete_type, $target_interface $(, $upstream_interface )+);
+        // Implement the ConcreteDivinator and Lineage traits for $concrete_type.
+        impl_concrete_divinator!($concrete_type, $target_interface $(, $upstream_interface )+
+                                 $( ; extends $parent_type )?);
+
+        // Fail to compile unless $concrete_type and $target_interface are registered via
+        // register_types!.
+        const _: () = <$concrete_type as Registered>::REGISTERED;
+        const _: () = <dyn $target_interface as Registered>::REGISTERED;
+
+        // Downcasts to $target_interface are coordinated by $global_downcasting_struct.
+        impl Downcastable for dyn $target_interface
+        {
+            type Downcaster = $global_downcasting_struct;
+        }
+
+        // Make $concrete_type downcastable to each upstream interface when registered.
+        impl DerivedCasts for $concrete_type
+        {
+            fn register_derived_casts()
+            {
+                $( provision_transmutation!(@register_derived $concrete_type,
+                                            $upstream_interface); )+
+            }
+        }
+        impl DerivedCasts for dyn $target_interface {}
 
         // Provision upcasting support for the public interfaces of all encompassing types.
         provision_upcasting!($upcast_trait, $target_interface, $cast, $cast_mut);
@@ -825,30 +4572,35 @@ macro_rules! provision_transmutation
         /// $downcast_trait: The trait that enables downcasting to $target_interface for all more
         ///                  abstract types.
         ///
+        /// Notes:
+        /// * Implemented for upstream interface trait objects of any lifetime, so downcasts may be
+        ///   invoked on borrowed trait objects within non-generic functions, e.g.
+        ///   fn abstract_func(iconstruct: &dyn IConstruct) { iconstruct.$cast(); }
+        ///
         pub trait $downcast_trait
         {
             ///
             /// $cast: When possible, downcasts this immutable (IConstruct) to an immutable
             ///        $target_interface.
             ///
-            fn $cast(&self) -> Option<&dyn $target_interface>;
+            fn $cast(&self) -> Option<&(dyn $target_interface + 'static)>;
 
             ///
             /// $cast_mut: When possible, downcasts this mutable (IConstruct) to a mutable
             ///            $target_interface.
             ///
-            fn $cast_mut(&mut self) -> Option<&mut dyn $target_interface>;
+            fn $cast_mut(&mut self) -> Option<&mut (dyn $target_interface + 'static)>;
         }
-        $( impl $downcast_trait for dyn $upstream_interface
+        $( impl<'a> $downcast_trait for dyn $upstream_interface + 'a
         {
-            fn $cast(&self) -> Option<&dyn $target_interface>
+            fn $cast(&self) -> Option<&(dyn $target_interface + 'static)>
             {
                 // Send this instance to $target_interface's global downcast handler to coordinate
                 // the downcast attempt.
                 $global_downcasting_struct::$cast(self)
             }
 
-            fn $cast_mut(&mut self) -> Option<&mut dyn $target_interface>
+            fn $cast_mut(&mut self) -> Option<&mut (dyn $target_interface + 'static)>
             {
                 // Send this instance to $target_interface's global downcast handler to coordinate
                 // the downcast attempt.
@@ -871,7 +4623,7 @@ macro_rules! provision_transmutation
         ///     objects are really &dyn IConstruct (or any other downcast prerequisite.)
         ///   * In these cases, $global_downcasting_struct can be invoked directly, e.g.
         ///       $global_downcasting_struct::$cast(iconstruct);
-        /// * For further information, see Issue #1 and Issue #3 in "Knowledge Base.md".
+        /// * For further information, see Issue #3 in "Knowledge Base.md".
         ///
         pub struct $global_downcasting_struct
         {
@@ -879,9 +4631,19 @@ macro_rules! provision_transmutation
             //       at least one field is kept private and no public constructor or Default
             //       implementation is added.
 
-            // Per-library downcasting handlers, indexed by the identifiers of the libraries they
-            // represent.
-            handlers: HashMap<LibraryIdentifier, Box<dyn $library_downcasting_trait>>
+            // Per-library downcasting handlers (each paired with the TypeId of its struct, in
+            // registration order), indexed by the identifiers of the libraries they represent.
+            handlers: HashMap<LibraryIdentifier,
+                              Vec<(TypeId, Box<dyn $library_downcasting_trait>)>>,
+
+            // The dispatch table of per-type downcasting thunks (registered by derived types, or
+            // supplied by registered handlers), indexed by the Rust TypeIds of the concrete types
+            // they represent.
+            dispatch_table: HashMap<TypeId,
+                                    (fn(&dyn IConstruct) ->
+                                       Option<&(dyn $target_interface + 'static)>,
+                                     fn(&mut dyn IConstruct) ->
+                                       Option<&mut (dyn $target_interface + 'static)>)>
         }
         impl $global_downcasting_struct
         {
@@ -907,149 +4669,605 @@ macro_rules! provision_transmutation
             ///                   handle downcasting more abstract (IConstructs) to
             ///                   $target_interfaces for the RDH library it represents.
             ///
+            /// Generic parameters:
+            /// * H ($library_downcasting_trait + 'static): The handler struct being registered.
+            ///
             /// Parameters:
             /// * library_identifier (LibraryIdentifier):
             ///   The identifier of the library whose downcasting handler is being registered.
-            /// * handler (Box<dyn $library_downcasting_trait>):
+            /// * handler (Box<H>):
             ///   The $library_downcasting_trait object that will handle downcasting requests for
             ///   the indicated RDH library.
             ///
+            /// Panics:
+            /// * Under any of the conditions for which try_register_handler() expresses an error.
+            ///
             /// Notes:
             /// * Callers yield ownership of both items sent to this function.
-            /// * This function will block until exclusive singleton instance access can be
-            ///   obtained, potentially forever.
+            /// * This function is a thin wrapper around try_register_handler().
+            /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for
+            ///   exclusive singleton instance access.
+            ///   * See this module's Implementation Notes.
+            ///
+            pub fn register_handler<H>(library_identifier: LibraryIdentifier, handler: Box<H>)
+                where H: $library_downcasting_trait + 'static
+            {
+                if let Err(error) = $global_downcasting_struct::try_register_handler(
+                                      library_identifier, handler)
+                {
+                    panic!("{}::register_handler(): {}", stringify!($global_downcasting_struct),
+                           error);
+                }
+            }
+
+            ///
+            /// try_register_handler: Attempts to register the specified
+            ///                       $library_downcasting_trait object to handle downcasting more
+            ///                       abstract (IConstructs) to $target_interfaces for the RDH
+            ///                       library it represents.
+            ///
+            /// Generic parameters:
+            /// * H ($library_downcasting_trait + 'static): The handler struct being registered.
+            ///
+            /// Parameters:
+            /// * library_identifier (LibraryIdentifier):
+            ///   The identifier of the library whose downcasting handler is being registered.
+            /// * handler (Box<H>):
+            ///   The $library_downcasting_trait object that will handle downcasting requests for
+            ///   the indicated RDH library.
+            ///
+            /// Expresses:
+            /// * If successful, Ok(()).
+            /// * If a handler of the same struct type has already been registered for the
+            ///   indicated library, Err(RegistryError::DuplicateHandler).
+            /// * If the TypeRegistry has been frozen, Err(RegistryError::Frozen).
+            /// * If exclusive singleton instance access couldn't be obtained in time,
+            ///   Err(RegistryError::Timeout).
+            /// * If the lock that guards the $global_downcasting_struct singleton instance has
+            ///   been poisoned, Err(RegistryError::PoisonedLock).
+            ///
+            /// Notes:
+            /// * Any number of handlers of distinct struct types may be registered for a single
+            ///   library, e.g. by independent modules or by other libraries contributing types
+            ///   defined there. They are consulted in registration order.
+            /// * Callers yield ownership of both items sent to this function (the handler is
+            ///   dropped if registration fails).
+            /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for
+            ///   exclusive singleton instance access.
             ///   * See this module's Implementation Notes.
             ///
-            pub fn register_handler(library_identifier: LibraryIdentifier,
-                                    handler: Box<dyn $library_downcasting_trait>)
+            pub fn try_register_handler<H>(library_identifier: LibraryIdentifier,
+                                           handler: Box<H>) -> Result<(), RegistryError>
+                where H: $library_downcasting_trait + 'static
             {
                 // Implementation note: It would be better to allow access only to developers
                 //                      extending, rather than consuming, the type hierarchy, but
                 //                      Rust has no way to model that across libraries.
 
-                let mut instance = unsafe { $global_downcasting_struct::rwlock() }.write().expect(
-                  "$global_downcasting_struct::register_handler(): Unable to acquire mutable \
+                let rwlock = $global_downcasting_struct::rwlock();
+                let mut instance = rwlock.write()?;
+                let handler_type = TypeId::of::<H>();
+                if instance.handlers.get(&library_identifier).is_some_and(
+                     |handlers| handlers.iter().any(|(registered, _)| *registered == handler_type))
+                {
+                    return Err(RegistryError::DuplicateHandler
+                               {
+                                   handler_name: stringify!($library_downcasting_trait),
+                                   library_identifier
+                               });
+                }
+                // Add the handler's thunks to the dispatch table, leaving any type already routed
+                // elsewhere untouched.
+                for (type_id, cast, cast_mut) in handler.thunks()
+                {
+                    instance.dispatch_table.entry(type_id).or_insert((cast, cast_mut));
+                }
+                instance.handlers.entry(library_identifier).or_default()
+                                 .push((handler_type, handler));
+                Ok(())
+            }
+
+            ///
+            /// register_derived_type: Registers the indicated derived concrete type so that its
+            ///                        (IConstructs) may be downcast to $target_interfaces,
+            ///                        regardless of the library in which it is defined.
+            ///
+            /// Generic parameters:
+            /// * C ($target_interface + 'static): The derived concrete type to register.
+            ///
+            /// Panics:
+            /// * Under any of the conditions for which try_register_derived_type() expresses an
+            ///   error.
+            ///
+            /// Notes:
+            /// * This function is a thin wrapper around try_register_derived_type().
+            /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for
+            ///   exclusive singleton instance access.
+            ///   * See this module's Implementation Notes.
+            ///
+            pub fn register_derived_type<C>() where C: $target_interface + 'static
+            {
+                if let Err(error) = $global_downcasting_struct::try_register_derived_type::<C>()
+                {
+                    panic!("{}::register_derived_type(): {}",
+                           stringify!($global_downcasting_struct), error);
+                }
+            }
+
+            ///
+            /// try_register_derived_type: Attempts to register the indicated derived concrete
+            ///                            type so that its (IConstructs) may be downcast to
+            ///                            $target_interfaces, regardless of the library in which
+            ///                            it is defined.
+            ///
+            /// Generic parameters:
+            /// * C ($target_interface + 'static): The derived concrete type to register.
+            ///
+            /// Expresses:
+            /// * If successful, Ok(()).
+            /// * If C has not been registered within the TypeRegistry,
+            ///   Err(RegistryError::UnregisteredType).
+            /// * If C has already been registered with $global_downcasting_struct (directly, or
+            ///   within the include [...] list of a registered handler),
+            ///   Err(RegistryError::DuplicateDerivedType).
+            /// * If the TypeRegistry has been frozen, Err(RegistryError::Frozen).
+            /// * If exclusive singleton instance access couldn't be obtained in time,
+            ///   Err(RegistryError::Timeout).
+            /// * If the lock that guards the $global_downcasting_struct singleton instance has
+            ///   been poisoned, Err(RegistryError::PoisonedLock).
+            ///
+            /// Notes:
+            /// * Intended to be invoked within the init() function of the library defining C, for
+            ///   each public interface introduced by C's ancestors, e.g.
+            ///     IVehicleDowncaster::register_derived_type::<Sedan>();
+            ///   * Types so registered need not be added to any include [...] list, so adding a
+            ///     new derived type never requires editing an ancestor's module.
+            /// * This function will wait no longer than FreezableLock::DEFAULT_TIMEOUT for
+            ///   exclusive singleton instance access.
+            ///   * See this module's Implementation Notes.
+            ///
+            pub fn try_register_derived_type<C>() -> Result<(), RegistryError>
+                where C: $target_interface + 'static
+            {
+                TypeRegistry::try_type_identifier_of::<C>()?;
+                let rwlock = $global_downcasting_struct::rwlock();
+                let mut instance = rwlock.write()?;
+                if instance.dispatch_table.contains_key(&TypeId::of::<C>()) == true
+                {
+                    return Err(RegistryError::DuplicateDerivedType
+                               {
+                                   downcaster_name: stringify!($global_downcasting_struct),
+                                   type_name: TypeRegistry::try_type_name_of::<C>()?
+                               });
+                }
+                instance.dispatch_table.insert(TypeId::of::<C>(),
+                                               ($global_downcasting_struct::derived_cast::<C>,
+                                                $global_downcasting_struct::derived_cast_mut::<C>));
+                Ok(())
+            }
+
+            ///
+            /// $cast: Downcasts the specified more abstract immutable (IConstruct) object to an
+            ///        immutable $target_interface object.
+            ///
+            /// Generic types:
+            /// * T (IConstruct + ?Sized): Represents all (IConstructs).
+            ///
+            /// Parameters:
+            /// * iconstruct (&T): The immutable more abstract (IConstruct) object to downcast to
+            ///                    an immutable $target_interface.
+            ///
+            /// Expresses:
+            /// * When successful, an Option<&dyn $target_interface> whose immutable object
+            ///   corresponds to the same concrete instance represented by the immutable
+            ///   (IConstruct) object.
+            /// * Otherwise, expresses None.
+            /// 
+            pub fn $cast<T>(iconstruct: &T) -> Option<&(dyn $target_interface + 'static)>
+                where T: IConstruct + ?Sized
+            {
+                // Route the downcast request to the thunk within the dispatch table for the
+                // specified (IConstruct's) underlying concrete type, or otherwise to the handlers
+                // registered for the RDH library in which that type was defined.
+                let rwlock = $global_downcasting_struct::rwlock();
+                let instance = rwlock.read().expect(
+                  "$global_downcasting_struct::$cast(): Unable to acquire immutable \
+                   $global_downcasting_struct singleton instance!");
+                instance.route(iconstruct.as_iconstruct())
+            }
+
+            ///
+            /// $cast_mut: Downcasts the specified more abstract mutable (IConstruct) object to a
+            ///            mutable $target_interface object.
+            ///
+            /// Generic types:
+            /// * T (IConstruct + ?Sized): Represents all (IConstructs).
+            ///
+            /// Parameters:
+            /// * iconstruct (&mut T): The mutable more abstract (IConstruct) object to downcast to
+            ///                        a mutable $target_interface.
+            ///
+            /// Expresses:
+            /// * When successful, an Option<&mut dyn $target_interface> whose mutable object
+            ///   corresponds to the same concrete instance represented by the mutable (IConstruct)
+            ///   object.
+            /// * Otherwise, expresses None.
+            ///
+            pub fn $cast_mut<T>(iconstruct: &mut T) ->
+              Option<&mut (dyn $target_interface + 'static)>
+                where T: IConstruct + ?Sized
+            {
+                // Route the downcast request to the thunk within the dispatch table for the
+                // specified (IConstruct's) underlying concrete type, or otherwise to the handlers
+                // registered for the RDH library in which that type was defined.
+                let rwlock = $global_downcasting_struct::rwlock();
+                let instance = rwlock.read().expect(
+                  "$global_downcasting_struct::$cast_mut(): Unable to acquire immutable \
                    $global_downcasting_struct singleton instance!");
-                if instance.handlers.contains_key(&library_identifier) == false
+                instance.route_mut(iconstruct.as_iconstruct_mut())
+            }
+
+            ///
+            /// try_downcast: Attempts to downcast the specified more abstract immutable
+            ///               (IConstruct) object to an immutable $target_interface object,
+            ///               describing any failure.
+            ///
+            /// Generic types:
+            /// * T (IConstruct + ?Sized): Represents all (IConstructs).
+            ///
+            /// Parameters:
+            /// * iconstruct (&T): The immutable more abstract (IConstruct) object to downcast to
+            ///                    an immutable $target_interface.
+            ///
+            /// Expresses:
+            /// * When successful, the immutable $target_interface object within Ok().
+            /// * Otherwise, a CastError describing the failure within Err().
+            ///
+            pub fn try_downcast<T>(iconstruct: &T) ->
+              Result<&(dyn $target_interface + 'static), CastError>
+                where T: IConstruct + ?Sized
+            {
+                let rwlock = $global_downcasting_struct::rwlock();
+                let instance = rwlock.read().map_err(
+                  |error| $global_downcasting_struct::unavailable(iconstruct.as_iconstruct(),
+                                                                   error))?;
+                match instance.route(iconstruct.as_iconstruct())
                 {
-                    instance.handlers.insert(library_identifier, handler);
+                    Some(target) => Ok(target),
+                    None => Err(instance.diagnose(iconstruct.as_iconstruct()))
                 }
-                else
+            }
+
+            ///
+            /// try_downcast_mut: Attempts to downcast the specified more abstract mutable
+            ///                   (IConstruct) object to a mutable $target_interface object,
+            ///                   describing any failure.
+            ///
+            /// Generic types:
+            /// * T (IConstruct + ?Sized): Represents all (IConstructs).
+            ///
+            /// Parameters:
+            /// * iconstruct (&mut T): The mutable more abstract (IConstruct) object to downcast to
+            ///                        a mutable $target_interface.
+            ///
+            /// Expresses:
+            /// * When successful, the mutable $target_interface object within Ok().
+            /// * Otherwise, a CastError describing the failure within Err().
+            ///
+            pub fn try_downcast_mut<T>(iconstruct: &mut T) ->
+              Result<&mut (dyn $target_interface + 'static), CastError>
+                where T: IConstruct + ?Sized
+            {
+                let rwlock = $global_downcasting_struct::rwlock();
+                let instance = rwlock.read().map_err(
+                  |error| $global_downcasting_struct::unavailable(iconstruct.as_iconstruct(),
+                                                                   error))?;
+
+                // Route through a raw pointer, as the borrow checker can't see that a failed
+                // route releases its mutable borrow (which diagnosing the failure requires).
+                let source = iconstruct.as_iconstruct_mut() as *mut dyn IConstruct;
+                match instance.route_mut(unsafe { &mut *source })
                 {
-                    panic!("$global_downcasting_struct::register_handler(): A \
-                           $library_downcasting_trait handler for the specified library has \
-                           already been registered within $global_downcasting_struct!");
+                    Some(target) => Ok(target),
+                    None => Err(instance.diagnose(unsafe { &*source }))
                 }
             }
 
             ///
-            /// $cast: Downcasts the specified more abstract immutable (IConstruct) object to an
-            ///        immutable $target_interface object.
+            /// downcast_box: Downcasts the specified boxed more abstract (IConstruct) object to a
+            ///               boxed $target_interface object, transferring ownership.
+            ///
+            /// Generic types:
+            /// * T (IConstruct + ?Sized): Represents all (IConstructs).
+            ///
+            /// Parameters:
+            /// * iconstruct (Box<T>): The boxed more abstract (IConstruct) object to downcast.
+            ///
+            /// Expresses:
+            /// * When successful, the same concrete instance as a Box<dyn $target_interface>
+            ///   within Ok().
+            /// * Otherwise, the original Box within Err().
+            ///
+            pub fn downcast_box<T>(iconstruct: Box<T>) ->
+              Result<Box<dyn $target_interface>, Box<T>>
+                where T: IConstruct + ?Sized
+            {
+                Transmuter::transmute_box(iconstruct, $global_downcasting_struct::$cast_mut)
+            }
+
+            ///
+            /// downcast_rc: Downcasts the specified reference-counted more abstract (IConstruct)
+            ///              object to a reference-counted $target_interface object, without
+            ///              cloning it.
             ///
             /// Generic types:
             /// * T (IConstruct + ?Sized): Represents all (IConstructs).
             ///
             /// Parameters:
-            /// * iconstruct (&T): The immutable more abstract (IConstruct) object to downcast to
-            ///                    an immutable $target_interface.
+            /// * iconstruct (Rc<T>): The reference-counted more abstract (IConstruct) object to
+            ///                       downcast.
             ///
             /// Expresses:
-            /// * When successful, an Option<&dyn $target_interface> whose immutable object
-            ///   corresponds to the same concrete instance represented by the immutable
-            ///   (IConstruct) object.
-            /// * Otherwise, expresses None.
-            /// 
-            pub fn $cast<T>(iconstruct: &T) -> Option<&dyn $target_interface>
+            /// * When successful, the same concrete instance as an Rc<dyn $target_interface>
+            ///   within Ok().
+            /// * Otherwise, the original Rc within Err().
+            ///
+            pub fn downcast_rc<T>(iconstruct: Rc<T>) -> Result<Rc<dyn $target_interface>, Rc<T>>
                 where T: IConstruct + ?Sized
             {
-                // Route the downcast request to the handler registered for the RDH library in
-                // which the specified (IConstruct's) underlying concrete type was defined.
-                let instance = unsafe { $global_downcasting_struct::rwlock() }.read().expect(
-                  "$global_downcasting_struct::$cast(): Unable to acquire immutable \
-                   $global_downcasting_struct singleton instance!");
-                let library_identifier = iconstruct.concrete_library_identifier();
-                if instance.handlers.contains_key(&library_identifier) == true
-                {
-                    instance.handlers[&library_identifier].$cast(iconstruct.as_iconstruct())
-                }
-                else
-                {
-                    None
-                }
+                Transmuter::transmute_rc(iconstruct, $global_downcasting_struct::$cast)
             }
 
             ///
-            /// $cast_mut: Downcasts the specified more abstract mutable (IConstruct) object to a
-            ///            mutable $target_interface object.
+            /// downcast_arc: Downcasts the specified atomically reference-counted more abstract
+            ///               (IConstruct) object to an atomically reference-counted
+            ///               $target_interface object, without cloning it.
             ///
             /// Generic types:
             /// * T (IConstruct + ?Sized): Represents all (IConstructs).
             ///
             /// Parameters:
-            /// * iconstruct (&mut T): The mutable more abstract (IConstruct) object to downcast to
-            ///                        a mutable $target_interface.
+            /// * iconstruct (Arc<T>): The atomically reference-counted more abstract (IConstruct)
+            ///                        object to downcast.
             ///
             /// Expresses:
-            /// * When successful, an Option<&mut dyn $target_interface> whose mutable object
-            ///   corresponds to the same concrete instance represented by the mutable (IConstruct)
-            ///   object.
-            /// * Otherwise, expresses None.
+            /// * When successful, the same concrete instance as an Arc<dyn $target_interface>
+            ///   within Ok().
+            /// * Otherwise, the original Arc within Err().
             ///
-            pub fn $cast_mut<T>(iconstruct: &mut T) -> Option<&mut dyn $target_interface>
+            pub fn downcast_arc<T>(iconstruct: Arc<T>) ->
+              Result<Arc<dyn $target_interface>, Arc<T>>
                 where T: IConstruct + ?Sized
             {
-                // Route the downcast request to the handler registered for the RDH library in
-                // which the specified (IConstruct's) underlying concrete type was defined.
-                let instance = unsafe { $global_downcasting_struct::rwlock() }.read().expect(
-                  "$global_downcasting_struct::$cast_mut(): Unable to acquire immutable \
-                   $global_downcasting_struct singleton instance!");
-                let library_identifier = iconstruct.concrete_library_identifier();
-                if instance.handlers.contains_key(&library_identifier) == true
+                Transmuter::transmute_arc(iconstruct, $global_downcasting_struct::$cast)
+            }
+
+            //
+            // *** Private functions ***
+            //
+
+            //
+            // diagnose: Determines why a downcast of the specified (IConstruct) failed.
+            //
+            // Parameters:
+            // * iconstruct (&dyn IConstruct): The (IConstruct) whose downcast failed.
+            //
+            // Expresses: The CastError describing the failure.
+            //
+            fn diagnose(&self, iconstruct: &dyn IConstruct) -> CastError
+            {
+                CastError::from_lookups(
+                  iconstruct.try_concrete_type_identifier(),
+                  TypeRegistry::try_cached_type_identifier_of::<dyn $target_interface>(),
+                  |source_type, target_type|
+                  {
+                      let library_identifier = source_type.library_identifier();
+                      match TypeRegistry::try_interfaces_of(source_type)
+                      {
+                          Err(error) => CastFailure::from(error),
+                          Ok(interfaces) if interfaces.contains(&target_type) == false =>
+                            CastFailure::NotTargetType,
+                          Ok(_) if self.handlers.is_empty() == true &&
+                                   self.dispatch_table.is_empty() == true =>
+                            CastFailure::NoHandler
+                            {
+                                downcaster_name: stringify!($global_downcasting_struct)
+                            },
+                          Ok(_) if self.handlers.contains_key(&library_identifier) == false =>
+                            CastFailure::UnregisteredLibrary
+                            {
+                                downcaster_name: stringify!($global_downcasting_struct),
+                                library_identifier
+                            },
+                          Ok(_) => CastFailure::NotIncluded
+                                   {
+                                       handler_name: stringify!($library_downcasting_trait)
+                                   }
+                      }
+                  })
+            }
+
+            //
+            // unavailable: Creates the CastError describing a downcast of the specified
+            //              (IConstruct) that failed because this struct's registry couldn't be
+            //              consulted.
+            //
+            // Parameters:
+            // * iconstruct (&dyn IConstruct): The (IConstruct) whose downcast failed.
+            // * error (RegistryError): The reason the registry couldn't be consulted.
+            //
+            // Expresses: The CastError describing the failure.
+            //
+            fn unavailable(iconstruct: &dyn IConstruct, error: RegistryError) -> CastError
+            {
+                CastError::from_lookups(
+                  iconstruct.try_concrete_type_identifier(),
+                  TypeRegistry::try_cached_type_identifier_of::<dyn $target_interface>(),
+                  |_, _| CastFailure::from(error))
+            }
+
+            //
+            // route: Routes a downcast request for the specified immutable (IConstruct) to the
+            //        thunk within the dispatch table for its concrete type, or otherwise to the
+            //        handlers registered for the library in which that type is defined.
+            //
+            // Parameters:
+            // * iconstruct (&dyn IConstruct): The immutable (IConstruct) to downcast.
+            //
+            // Expresses:
+            // * If successful, the immutable $target_interface object within Some().
+            // * Otherwise expresses None.
+            //
+            fn route<'a>(&self, iconstruct: &'a dyn IConstruct) ->
+              Option<&'a (dyn $target_interface + 'static)>
+            {
+                match self.dispatch_table.get(&iconstruct.concrete_type_id())
                 {
-                    instance.handlers[&library_identifier]
-                      .$cast_mut(iconstruct.as_iconstruct_mut())
+                    Some((cast, _)) => cast(iconstruct),
+                    None =>
+                    {
+                        let source_type = iconstruct.try_concrete_type_identifier().ok()?;
+                        self.handlers.get(&source_type.library_identifier())
+                                     .and_then(|handlers| handlers.iter().find_map(
+                                       |(_, handler)| handler.$cast(iconstruct)))
+                    }
                 }
-                else
+            }
+
+            //
+            // route_mut: Routes a downcast request for the specified mutable (IConstruct) to the
+            //            thunk within the dispatch table for its concrete type, or otherwise to the
+            //            handlers registered for the library in which that type is defined.
+            //
+            // Parameters:
+            // * iconstruct (&mut dyn IConstruct): The mutable (IConstruct) to downcast.
+            //
+            // Expresses:
+            // * If successful, the mutable $target_interface object within Some().
+            // * Otherwise expresses None.
+            //
+            fn route_mut<'a>(&self, iconstruct: &'a mut dyn IConstruct) ->
+              Option<&'a mut (dyn $target_interface + 'static)>
+            {
+                match self.dispatch_table.get(&iconstruct.concrete_type_id())
                 {
-                    None
+                    Some((_, cast_mut)) => cast_mut(iconstruct),
+                    None =>
+                    {
+                        // Find the first handler able to perform the cast before performing it
+                        // mutably, as the mutable borrow can't be released between attempts.
+                        let source_type = iconstruct.try_concrete_type_identifier().ok()?;
+                        match self.handlers.get(&source_type.library_identifier())
+                                  .and_then(|handlers| handlers.iter().find(
+                                    |(_, handler)| handler.$cast(&*iconstruct).is_some()))
+                        {
+                            Some((_, handler)) => handler.$cast_mut(iconstruct),
+                            None => None
+                        }
+                    }
                 }
             }
 
             //
-            // *** Private functions ***
+            // derived_cast: The thunk through which immutable (IConstructs) whose concrete type
+            //               is C are downcast to $target_interfaces.
+            //
+            fn derived_cast<C>(iconstruct: &dyn IConstruct) ->
+              Option<&(dyn $target_interface + 'static)>
+                where C: $target_interface + 'static
+            {
+                iconstruct.as_concrete::<C>().map(|concrete| concrete as &dyn $target_interface)
+            }
+
+            //
+            // derived_cast_mut: The thunk through which mutable (IConstructs) whose concrete type
+            //                   is C are downcast to $target_interfaces.
+            //
+            fn derived_cast_mut<C>(iconstruct: &mut dyn IConstruct) ->
+              Option<&mut (dyn $target_interface + 'static)>
+                where C: $target_interface + 'static
+            {
+                iconstruct.as_concrete_mut::<C>()
+                          .map(|concrete| concrete as &mut dyn $target_interface)
+            }
+
+            //
+            // empty: Creates an empty $global_downcasting_struct instance, guarded by a new
+            //        FreezableLock.
             //
+            fn empty() -> FreezableLock<$global_downcasting_struct>
+            {
+                // Enlist this struct to be frozen along with the TypeRegistry. Should that fail
+                // (e.g. because the TypeRegistry is already frozen), it freezes itself upon being
+                // read instead.
+                let _ = TypeRegistry::try_enlist_freezer($global_downcasting_struct::freeze);
+                FreezableLock::new(stringify!($global_downcasting_struct),
+                                   $global_downcasting_struct
+                                   {
+                                       handlers: HashMap::new(),
+                                       dispatch_table: HashMap::new()
+                                   })
+            }
+
+            //
+            // freeze: Freezes the $global_downcasting_struct singleton (or scoped) instance.
+            //
+            fn freeze() -> Result<(), RegistryError>
+            {
+                $global_downcasting_struct::rwlock().freeze()
+            }
 
             //
             // rwlock: Obtains a reference to the reader/writer lock that guards access to the
             //         singleton $global_downcasting_struct instance (creating both when
-            //         necessary).
+            //         necessary), or to the $global_downcasting_struct instance of the
+            //         RegistryScope current on this thread, if any.
             //
-            // Expresses: A reference to the lock that guards the singleton instance.
+            // Expresses: The lock that guards the singleton (or scoped) instance.
             //
-            unsafe fn rwlock<'a>() -> &'a RwLock<$global_downcasting_struct>
+            fn rwlock() -> RegistryLock<$global_downcasting_struct>
             {
                 // Implementation note: Structs may not contain static fields, so we have to
                 //                      declare the singleton instance and the rwlock that guards
                 //                      it here (which has its advantages).
 
-                // The RwLock, embedded within an Option, that guards the singleton
-                //  $global_downcasting_struct instance.
-                static mut RWLOCK: Option<RwLock<$global_downcasting_struct>> = None;
-        
-                // Create the rwlock and singleton instance the first time this function is called
-                // (while all other calling threads are blocked).
-                static ONCE: Once = Once::new();
-                ONCE.call_once(||
-                               {
-                                   RWLOCK = Some(RwLock::new($global_downcasting_struct
-                                                 {
-                                                     handlers: HashMap::new()
-                                                 }));
-                               });
-        
-                RWLOCK.as_ref().expect("$global_downcasting_struct::rwlock(): Unable to acquire \
-                                        the lock that guards the $global_downcasting_struct \
-                                        singleton instance!")
+                // The FreezableLock that guards the singleton $global_downcasting_struct
+                // instance, created the first time it's needed (while all other calling threads
+                // are blocked).
+                static RWLOCK: OnceLock<FreezableLock<$global_downcasting_struct>> =
+                  OnceLock::new();
+
+                RegistryScope::current_lock($global_downcasting_struct::empty).unwrap_or_else(
+                  || RegistryLock::Global(RWLOCK.get_or_init($global_downcasting_struct::empty)))
+            }
+        }
+
+        //
+        // CastTarget implementation for $target_interface.
+        //
+        impl CastTarget for dyn $target_interface
+        {
+            fn cast_from<T>(iconstruct: &T) -> Option<&Self> where T: IConstruct + ?Sized
+            {
+                $global_downcasting_struct::$cast(iconstruct)
+            }
+
+            fn cast_from_mut<T>(iconstruct: &mut T) -> Option<&mut Self>
+                where T: IConstruct + ?Sized
+            {
+                $global_downcasting_struct::$cast_mut(iconstruct)
+            }
+
+            fn try_cast_from<T>(iconstruct: &T) -> Result<&Self, CastError>
+                where T: IConstruct + ?Sized
+            {
+                $global_downcasting_struct::try_downcast(iconstruct)
+            }
+
+            fn try_cast_from_mut<T>(iconstruct: &mut T) -> Result<&mut Self, CastError>
+                where T: IConstruct + ?Sized
+            {
+                $global_downcasting_struct::try_downcast_mut(iconstruct)
             }
         }
 
@@ -1058,7 +5276,11 @@ macro_rules! provision_transmutation
         ///                             $target_interface for those underlying concrete types that
         ///                             are defined within the library they represent.
         ///
-        pub trait $library_downcasting_trait
+        /// Notes:
+        /// * Handlers are owned by the $global_downcasting_struct singleton, which may be accessed
+        ///   from any thread, so they must be Send and Sync.
+        ///
+        pub trait $library_downcasting_trait: Send + Sync
         {
             // Implementation note: It would be better to allow access only to developers
             //                      extending, rather than consuming, the type hierarchy, but Rust
@@ -1080,7 +5302,7 @@ macro_rules! provision_transmutation
             /// * Otherwise expresses None.
             ///
             fn $cast<'a>(&self, iconstruct: &'a dyn IConstruct) ->
-              Option<&'a dyn $target_interface>;
+              Option<&'a (dyn $target_interface + 'static)>;
 
             ///
             /// $cast_mut: Downcasts the specified mutable (IConstruct) to a mutable
@@ -1098,7 +5320,30 @@ macro_rules! provision_transmutation
             /// * Otherwise expresses None.
             ///
             fn $cast_mut<'a>(&self, iconstruct: &'a mut dyn IConstruct) ->
-              Option<&'a mut dyn $target_interface>;
+              Option<&'a mut (dyn $target_interface + 'static)>;
+
+            ///
+            /// thunks: Retrieves the downcasting thunks for each concrete type this handler
+            ///         supports, for entry within the dispatch table of
+            ///         $global_downcasting_struct.
+            ///
+            /// Expresses: The Rust TypeId of each supported concrete type, paired with the thunks
+            ///            that downcast its immutable and mutable (IConstructs).
+            ///
+            /// Notes:
+            /// * Handlers created via extend_downcasting! supply one entry per type within their
+            ///   include [...] list, so downcasts to those types cost a single table lookup.
+            /// * The default implementation supplies none, in which case the handler's $cast(_mut)
+            ///   methods are consulted instead.
+            ///
+            fn thunks(&self) -> Vec<(TypeId,
+                                     fn(&dyn IConstruct) ->
+                                       Option<&(dyn $target_interface + 'static)>,
+                                     fn(&mut dyn IConstruct) ->
+                                       Option<&mut (dyn $target_interface + 'static)>)>
+            {
+                Vec::new()
+            }
         }
         // Add the current library's $library_downcasting_struct and its implementation of
         // $library_downcasting_trait via a companion public macro:
@@ -1151,15 +5396,15 @@ macro_rules! provision_transmutation
 /// ```
 ///
 /// Notes:
-/// * Each of these extensions must also be complemented by a runtime call within library init
-///   function, e.g.:
+/// * Each of these extensions must also be complemented by listing $library_downcasting_struct
+///   within the handlers [...] of its module's register_types! invocation (otherwise, compilation
+///   fails), e.g.:
 ///
 /// ```
-///    pub fn init()
+///    register_types!
 ///    {
 ///         ...
-///        ICarDowncaster::register_handler(TypeRegistry::library_identifier_of::<FullSizeSedan>(),
-///                                         Box::new(DowncastsSecondExtensionTypesToICar{}));
+///        handlers [ICarDowncaster: DowncastsSecondExtensionTypesToICar]
 ///    }
 /// ```
 ///
@@ -1188,6 +5433,11 @@ macro_rules! extend_downcasting
             // extending, rather than consuming, the type hierarchy, but Rust has no way to model
             // that across libraries.
         }
+
+        // Fail to compile unless $library_downcasting_struct is registered via register_types!.
+        const _: () = <$library_downcasting_struct as Registered>::REGISTERED;
+
+
         impl $library_downcasting_trait for $library_downcasting_struct
         {
             //
@@ -1206,9 +5456,9 @@ macro_rules! extend_downcasting
             // * Otherwise expresses None.
             //
             fn $cast<'a>(&self, iconstruct: &'a dyn IConstruct) ->
-              Option<&'a dyn $target_interface>
+              Option<&'a (dyn $target_interface + 'static)>
             {
-                if iconstruct.is(TypeRegistry::type_identifier_of::<$concrete_type>())
+                if iconstruct.concrete_type_id() == TypeId::of::<$concrete_type>()
                 {
                     if let Some(construct) = iconstruct.as_concrete::<$concrete_type>()
                     {
@@ -1219,7 +5469,7 @@ macro_rules! extend_downcasting
                         None
                     }
                 } $(
-                else if iconstruct.is(TypeRegistry::type_identifier_of::<$library_concrete_type>())
+                else if iconstruct.concrete_type_id() == TypeId::of::<$library_concrete_type>()
                 {
                     if let Some(construct) = iconstruct.as_concrete::<$library_concrete_type>()
                     {
@@ -1252,9 +5502,9 @@ macro_rules! extend_downcasting
             // * Otherwise expresses None.
             //
             fn $cast_mut<'a>(&self, iconstruct: &'a mut dyn IConstruct) ->
-              Option<&'a mut dyn $target_interface>
+              Option<&'a mut (dyn $target_interface + 'static)>
             {
-                if iconstruct.is(TypeRegistry::type_identifier_of::<$concrete_type>())
+                if iconstruct.concrete_type_id() == TypeId::of::<$concrete_type>()
                 {
                     if let Some(construct) = iconstruct.as_concrete_mut::<$concrete_type>()
                     {
@@ -1265,7 +5515,7 @@ macro_rules! extend_downcasting
                         None
                     }
                 } $(
-                else if iconstruct.is(TypeRegistry::type_identifier_of::<$library_concrete_type>())
+                else if iconstruct.concrete_type_id() == TypeId::of::<$library_concrete_type>()
                 {
                     if let Some(construct) = iconstruct.as_concrete_mut::<$library_concrete_type>()
                     {
@@ -1281,7 +5531,125 @@ macro_rules! extend_downcasting
                     None
                 }
             }
+
+            //
+            // thunks: Retrieves the downcasting thunks for each concrete type within the include
+            //         [...] list, for entry within the global dispatch table.
+            //
+            // Expresses: The Rust TypeId of each included concrete type, paired with the thunks
+            //            that downcast its immutable and mutable (IConstructs).
+            //
+            fn thunks(&self) -> Vec<(TypeId,
+                                     fn(&dyn IConstruct) ->
+                                       Option<&(dyn $target_interface + 'static)>,
+                                     fn(&mut dyn IConstruct) ->
+                                       Option<&mut (dyn $target_interface + 'static)>)>
+            {
+                // The thunk through which immutable (IConstructs) whose concrete type is C are
+                // downcast to $target_interfaces.
+                fn cast<C>(iconstruct: &dyn IConstruct) ->
+                  Option<&(dyn $target_interface + 'static)>
+                    where C: $target_interface + 'static
+                {
+                    iconstruct.as_concrete::<C>().map(|concrete| concrete as &dyn $target_interface)
+                }
+
+                // The thunk through which mutable (IConstructs) whose concrete type is C are
+                // downcast to $target_interfaces.
+                fn cast_mut<C>(iconstruct: &mut dyn IConstruct) ->
+                  Option<&mut (dyn $target_interface + 'static)>
+                    where C: $target_interface + 'static
+                {
+                    iconstruct.as_concrete_mut::<C>()
+                              .map(|concrete| concrete as &mut dyn $target_interface)
+                }
+
+                vec![(TypeId::of::<$concrete_type>(), cast::<$concrete_type>,
+                      cast_mut::<$concrete_type>)
+                     $( , (TypeId::of::<$library_concrete_type>(), cast::<$library_concrete_type>,
+                           cast_mut::<$library_concrete_type>) )*]
+            }
+        }
+    }
+}
+
+///
+/// register_types: Generates the register_types() function through which the current module
+///                 registers its RDH types, generic type families, and downcast handlers, and
+///                 implements the Registered trait for each of them.
+///
+/// Parameters (each clause is optional, but those present must appear in this order):
+/// * $type (ty): The interfaces (as dyn $type) and concrete types defined within this module.
+/// * $type_uuid (expr): The unique identifier of the preceding $type.
+/// * $family (ty): The marker types of the generic type families defined within this module.
+/// * $family_uuid (expr): The unique identifier of the preceding $family.
+/// * $member_family (ty): The family to which the following $member belongs.
+/// * $member (ty): The instantiations of generic types used by this library.
+/// * $member_uuid (expr): The unique identifier of the preceding $member.
+/// * $global_downcasting_struct (ident): The global downcasting struct with which the following
+///                                       $library_downcasting_struct is registered.
+/// * $library_downcasting_struct (ident): The downcast handlers provisioned within this module via
+///                                        provision_transmutation! or extend_downcasting!.
+///
+/// # Example invocation:
+///
+/// ```
+///  register_types!
+///  {
+///      types [dyn ISedan = ISEDAN_TYPE_UUID, Sedan = SEDAN_TYPE_UUID]
+///      families [WheelFamily = WHEEL_FAMILY_UUID]
+///      members [WheelFamily: Wheel<Alloy> = WHEEL_ALLOY_TYPE_UUID]
+///      handlers [ISedanDowncaster: DowncastsRdhExtensionTypesToISedan]
+///  }
+/// ```
+///
+/// Notes:
+/// * Invoked once per module, within the module's Infrastructure section. The generated function
+///   takes the UUID of the library being initialized, and is invoked by that library's Library
+///   static (see provision_library!).
+///   * When a module isn't listed within provision_library!, its register_types() function is
+///     never invoked, so the compiler reports its UUID constants as never used.
+/// * provision_transmutation! and extend_downcasting! require the Registered trait for each type,
+///   interface, and downcast handler they provision, so none can be silently left unregistered.
+/// * Each concrete type within the types [...] clause is then registered with the downcasting
+///   struct of each public interface introduced by its ancestors (see DerivedCasts), so that its
+///   (IConstructs) may be downcast to them.
+/// * Within each clause, entries are registered in the order listed, so interfaces should precede
+///   the concrete types that introduce them.
+///
+#[macro_export]
+macro_rules! register_types
+{
+    {
+        $( types [$( $type: ty = $type_uuid: expr ),+ $(,)?] )?
+        $( families [$( $family: ty = $family_uuid: expr ),+ $(,)?] )?
+        $( members [$( $member_family: ty: $member: ty = $member_uuid: expr ),+ $(,)?] )?
+        $( handlers [$( $global_downcasting_struct: ident: $library_downcasting_struct: ident ),+
+                     $(,)?] )?
+    } =>
+    {
+        ///
+        /// register_types: Registers the RDH types, generic type families, and downcast handlers
+        ///                 defined within this module.
+        ///
+        /// Parameters:
+        /// * library_uuid (u128): The unique identifier of the library being initialized.
+        ///
+        pub(crate) fn register_types(library_uuid: u128)
+        {
+            $( $( TypeRegistry::register_type::<$type>($type_uuid, library_uuid); )+ )?
+            $( $( TypeRegistry::register_family::<$family>($family_uuid, library_uuid); )+ )?
+            $( $( TypeRegistry::register_family_member::<$member, $member_family>($member_uuid);
+            )+ )?
+            $( $( $global_downcasting_struct::register_handler(
+                    LibraryIdentifier::from_uuid(library_uuid),
+                    Box::new($library_downcasting_struct{})); )+ )?
+            $( $( <$type as DerivedCasts>::register_derived_casts(); )+ )?
         }
+
+        $( $( impl Registered for $type {} )+ )?
+        $( $( impl Registered for $member {} )+ )?
+        $( $( impl Registered for $library_downcasting_struct {} )+ )?
     }
 }
 
@@ -1310,18 +5678,148 @@ macro_rules! extend_downcasting
 // * $implemented_interface (ident): Additional public interfaces implemented by the concrete
 //                                   (Construct) type, if any.
 //
-// Example invocation:
-//  impl_concrete_divinator!(Car, ICar, IVehicle, IConstruct);
+// * $generic (ident): The generic type parameters of $concrete_type, if any.
+// * $parent_type (ty): The concrete type directly encompassed by $concrete_type, if any.
+//
+// Example invocations:
+//  impl_concrete_divinator!(Construct, IConstruct);
+//  impl_concrete_divinator!(Car, ICar, IVehicle, IConstruct; extends Vehicle);
+//  impl_concrete_divinator!(<T> Property<T>, IProperty, IConstruct; extends Construct);
+//
+// Notes:
+// * In addition to ConcreteDivinator, this macro implements the Lineage trait for both
+//   $concrete_type and its introduced interface.
 //
 #[macro_export]
 macro_rules! impl_concrete_divinator
 {
-    ($concrete_type: ty, $introduced_interface: ident $( , $implemented_interface: ident)*) =>
+    // Implementation note: The generic arm must precede the non-generic arm, as a failed attempt
+    //                      to parse "<T> ..." as a type would otherwise abort expansion.
+
+    (<$($generic: ident),+> $concrete_type: ty, $introduced_interface: ident
+                                                $( , $implemented_interface: ident)*
+                                                $( ; extends $parent_type: ty)?) =>
+    {
+        impl_concrete_divinator!(@impl [$($generic: 'static),+] $concrete_type,
+                                                                $introduced_interface
+                                                                $( , $implemented_interface)*;
+                                                                [$($parent_type)?]);
+    };
+
+    ($concrete_type: ty, $introduced_interface: ident $( , $implemented_interface: ident)*
+                                                      $( ; extends $parent_type: ty)?) =>
+    {
+        impl_concrete_divinator!(@impl [] $concrete_type, $introduced_interface
+                                          $( , $implemented_interface)*; [$($parent_type)?]);
+    };
+
+    (@parent_type_id []) =>
+    {
+        None
+    };
+
+    (@parent_type_id [$parent_type: ty]) =>
+    {
+        Some(TypeId::of::<$parent_type>())
+    };
+
+    (@parent_accessors []) =>
+    {
+        None
+    };
+
+    (@parent_accessors [$parent_type: ty]) =>
+    {
+        Some(ParentAccessors::of::<Self, $parent_type>())
+    };
+
+    (@identity_cache []) =>
+    {
+        {
+            // The identity cache for this (non-generic) type.
+            static IDENTITY_CACHE: OnceLock<(TypeIdentifier, Option<TypeIdentifier>)> =
+              OnceLock::new();
+            Some(&IDENTITY_CACHE)
+        }
+    };
+
+    (@identity_cache [$($impl_generics: tt)+]) =>
     {
+        None
+    };
+
+    (@impl [$($impl_generics: tt)*] $concrete_type: ty, $introduced_interface: ident
+                                                        $( , $implemented_interface: ident)*;
+                                                        [$($parent_type: ty)?]) =>
+    {
+        //
+        // Lineage implementation for $concrete_type.
+        //
+        // Safety: parent_type_id() and parent_accessors() are both derived from $parent_type (or
+        //         are both None).
+        //
+        unsafe impl<$($impl_generics)*> Lineage for $concrete_type
+        {
+            const KIND: TypeKind = TypeKind::Concrete;
+
+            fn parent_type_id() -> Option<TypeId>
+            {
+                impl_concrete_divinator!(@parent_type_id [$($parent_type)?])
+            }
+
+            fn parent_accessors() -> Option<ParentAccessors>
+            {
+                impl_concrete_divinator!(@parent_accessors [$($parent_type)?])
+            }
+
+            fn interface_type_ids() -> Vec<TypeId>
+            {
+                vec![TypeId::of::<dyn $introduced_interface>()
+                     $( , TypeId::of::<dyn $implemented_interface>() )*]
+            }
+
+            fn identity_cache() ->
+              Option<&'static OnceLock<(TypeIdentifier, Option<TypeIdentifier>)>>
+            {
+                impl_concrete_divinator!(@identity_cache [$($impl_generics)*])
+            }
+        }
+
+        //
+        // Lineage implementation for $introduced_interface.
+        //
+        // Safety: Public interfaces have neither parent type ids nor parent accessors.
+        //
+        unsafe impl Lineage for dyn $introduced_interface
+        {
+            const KIND: TypeKind = TypeKind::Interface;
+
+            fn parent_type_id() -> Option<TypeId>
+            {
+                None
+            }
+
+            fn parent_accessors() -> Option<ParentAccessors>
+            {
+                None
+            }
+
+            fn interface_type_ids() -> Vec<TypeId>
+            {
+                vec![$( TypeId::of::<dyn $implemented_interface>() ),*]
+            }
+
+            fn identity_cache() ->
+              Option<&'static OnceLock<(TypeIdentifier, Option<TypeIdentifier>)>>
+            {
+                impl_concrete_divinator!(@identity_cache [])
+            }
+        }
+
         //
         // ConcreteDivinator implementation for $concrete_type.
         //
-        impl ConcreteDivinator for $concrete_type
+        impl<$($impl_generics)*> ConcreteDivinator for $concrete_type
         {
             //
             // concrete_type_identifier: Retrieves the identifier for the current instance's
@@ -1332,6 +5830,15 @@ macro_rules! impl_concrete_divinator
                 self.type_identifier()
             }
 
+            //
+            // try_concrete_type_identifier: Attempts to retrieve the identifier for the current
+            //                               instance's concrete type.
+            //
+            fn try_concrete_type_identifier(&self) -> Result<TypeIdentifier, RegistryError>
+            {
+                TypeRegistry::try_cached_type_identifier_of::<Self>()
+            }
+
             //
             // concrete_library_identifier: Retrieves the identifier for the library in which the
             //                              current instance's concrete type is defined.
@@ -1341,6 +5848,14 @@ macro_rules! impl_concrete_divinator
                 self.library_identifier()
             }
 
+            //
+            // concrete_type_id: Retrieves the Rust TypeId of the current instance's concrete type.
+            //
+            fn concrete_type_id(&self) -> TypeId
+            {
+                TypeId::of::<Self>()
+            }
+
             //
             // implements: Determines whether this object's concrete type implements the indicated
             //             public interface.
@@ -1350,17 +5865,29 @@ macro_rules! impl_concrete_divinator
             //                                    for.
             //
             // Expresses:
-            // * If this object's concrete type implements the indicated public interface,
-            //   expresses true.
+            // * If this object's concrete type implements the indicated public interface, or a
+            //   member of the indicated generic interface family, expresses true.
             // * Otherwise, expresses false.
             //
             fn implements(&self, interface_type: TypeIdentifier) -> bool  
             {
-                interface_type == TypeRegistry::type_identifier_of::<dyn $introduced_interface>()
-                $(
-                || interface_type ==
-                     TypeRegistry::type_identifier_of::<dyn $implemented_interface>()
-                )*
+                let implemented_interfaces =
+                [
+                    TypeRegistry::try_cached_type_identifier_of::<dyn $introduced_interface>().ok()
+                    $(
+                    , TypeRegistry::try_cached_type_identifier_of::<dyn $implemented_interface>()
+                                  .ok()
+                    )*
+                ];
+                let implemented_families =
+                [
+                    TypeRegistry::try_family_of::<dyn $introduced_interface>().ok().flatten()
+                    $(
+                    , TypeRegistry::try_family_of::<dyn $implemented_interface>().ok().flatten()
+                    )*
+                ];
+                implemented_interfaces.contains(&Some(interface_type)) ||
+                implemented_families.contains(&Some(interface_type))
             }
 
             //
@@ -1371,15 +5898,17 @@ macro_rules! impl_concrete_divinator
             // * test_type (TypeIdentifier): The type identifier of the type being tested.
             //
             // Expresses:
-            // * If this object's concrete type *literally* is the indicated type, expresses true.
+            // * If this object's concrete type *literally* is the indicated type, or is a member
+            //   of the indicated generic type family, expresses true.
             // * Otherwise, expresses false.
             //
             // IMPORTANT DISTINCTIONS FROM INHERITANCE-BASED LANGUAGES:
             // * Although in languages such as C#, "is" will also return true if test_type is an
             //   ancestor of this object's type or one of its implemented interfaces, this function
             //   will return *false*.
-            //   * The *only* time "is" will return true is when the specified TypeIdentifier is
-            //     the *exact* type identifier for this object's concrete type.
+            //   * The *only* times "is" will return true are when the specified TypeIdentifier is
+            //     the *exact* type identifier for this object's concrete type, or the identifier
+            //     of the generic family to which it belongs.
             // * To determine whether a type implements a given interface, call implements() rather
             //   than is().
             // * To determine whether a type is "derived" from another type (or, really,
@@ -1410,7 +5939,32 @@ macro_rules! impl_concrete_divinator
             //
             fn is(&self, test_type: TypeIdentifier) -> bool
             {
-                self.type_identifier() == test_type
+                TypeRegistry::try_cached_type_identifier_of::<Self>() == Ok(test_type) ||
+                TypeRegistry::try_family_of::<Self>() == Ok(Some(test_type))
+            }
+
+            //
+            // is_a: Determines whether this object's concrete type is the indicated concrete type
+            //       or encompasses it, directly or indirectly (i.e. "inherits" from it).
+            //
+            // Parameters:
+            // * test_type (TypeIdentifier): The type identifier of the concrete type (or generic
+            //                               family) being tested.
+            //
+            // Expresses:
+            // * If is() would express true, or if the indicated type (or a member of the indicated
+            //   generic family) is an ancestor of this object's concrete type, expresses true.
+            // * Otherwise, expresses false.
+            //
+            fn is_a(&self, test_type: TypeIdentifier) -> bool
+            {
+                let type_identifier = match TypeRegistry::try_cached_type_identifier_of::<Self>()
+                {
+                    Ok(type_identifier) => type_identifier,
+                    Err(_) => return false
+                };
+                self.is(test_type) ||
+                TypeRegistry::try_inherits_from(type_identifier, test_type) == Ok(true)
             }
         }
     }
@@ -1502,7 +6056,21 @@ macro_rules! provision_upcasting
 //
 
 // Traits and types
-use std::any;
+use std::any::{ self, Any, TypeId };
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{ Deref, DerefMut };
+use std::str::FromStr;
 use std::collections::HashMap;
-use std::sync::{ Once, RwLock };
+use std::rc::Rc;
+use std::sync::{ Arc, LazyLock, OnceLock };
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::time::Duration;
+use crate::_infrastructure::reader_writer_lock::{ LockError, LockRecursionPolicy, ReadLockGuard,
+                                                  ReaderWriterLock, WriteLockGuard };
 use crate::_hierarchy::construct::IConstruct;
+use crate::_infrastructure::uuid::{ parse_uuid, parse_uuid_at, uuid_string, write_uuid,
+                                   UUID_TEXT_LENGTH };
@@ -0,0 +1,388 @@
+// ************************************************************************************************
+// Copyright 2023 Gene DeClark and Contributors within this file's version control history
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+// ************************************************************************************************
+
+//!
+//! uuid.rs
+//!
+//! UUIDs: The compile-time derivation and parsing of the u128 UUIDs that identify RDH types and
+//!        libraries.
+//!
+//!  Contains:
+//!  * name_uuid() and name_uuid!: Derive a stable, name-based (RFC 4122 version 5) UUID from a
+//!    namespace UUID (e.g. the library's) and a name (e.g. the type's path), so that new types
+//!    may be given collision-resistant identifiers without an external generator.
+//!  * parse_uuid() and uuid!: Parse a hyphenated hex UUID (e.g. one copied from a generator or a
+//!    TypeIdentifier's Display text), rejecting malformed UUID literals at compile time.
+//!
+//! THIS IS A WORK IN PROGRESS.
+//!   Although broad strokes will be retained, at this time, everything is subject to change
+//!   without notice.
+//!
+
+//
+// Implementation notes:
+//
+// * Name-based UUIDs are derived exactly as RFC 4122 specifies for version 5 UUIDs: the SHA-1
+//   digest of the namespace UUID's big-endian bytes followed by the name's UTF-8 bytes, truncated
+//   to 16 bytes, with the version and variant bits overwritten.
+//   * Hence they match those produced by other languages' UUID libraries (e.g. Python's
+//     uuid.uuid5()), and may be checked against them.
+//   * SHA-1 is no longer suitable for cryptographic purposes, but collision resistance among
+//     honestly chosen names is all that's required here.
+// * Everything is implemented as const fns so that identifiers may be derived within the
+//   initializers of constants, at no run-time cost.
+//
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Public functions
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// name_uuid: Derives the name-based (RFC 4122 version 5) UUID for the specified name within the
+///            indicated namespace.
+///
+/// Parameters:
+/// * namespace (u128): The UUID of the namespace within which the name is unique, e.g. the UUID of
+///                     the library that defines the type being identified.
+/// * name (&str): The name to identify, e.g. the type's path.
+///
+/// Expresses: The derived UUID, which is always the same for the same namespace and name.
+///
+/// Notes:
+/// * Prefer the name_uuid! macro, which guarantees that the UUID is derived at compile time.
+/// * Renaming a type (or moving it to another module) doesn't change the name its UUID was derived
+///   from; its UUID must never change, even if that name no longer matches its path.
+///
+pub const fn name_uuid(namespace: u128, name: &str) -> u128
+{
+    let digest = sha1(namespace.to_be_bytes(), name.as_bytes());
+    let mut bytes = [0u8; 16];
+    let mut index = 0;
+    while index < 16
+    {
+        bytes[index] = digest[index];
+        index += 1;
+    }
+
+    // Overwrite the version (5: name-based, SHA-1) and variant (RFC 4122) bits.
+    bytes[6] = (bytes[6] & 0x0f) | 0x50;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    u128::from_be_bytes(bytes)
+}
+
+///
+/// parse_uuid: Parses the specified hyphenated hex UUID (8-4-4-4-12 digits, of either case).
+///
+/// Parameters:
+/// * text (&str): The UUID's text, e.g. "1f210cc2-799f-4bd0-9669-ea80610818b8".
+///
+/// Expresses:
+/// * If successful, the UUID.
+/// * Otherwise, a ParseIdentifierError indicating why the text couldn't be parsed.
+///
+/// Notes:
+/// * Prefer the uuid! macro for UUID literals, which rejects malformed literals at compile time.
+///
+pub const fn parse_uuid(text: &str) -> Result<u128, ParseIdentifierError>
+{
+    let bytes = text.as_bytes();
+    if bytes.len() != UUID_TEXT_LENGTH
+    {
+        return Err(ParseIdentifierError::InvalidLength { length: bytes.len() });
+    }
+    parse_uuid_at(bytes, 0)
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Public macros
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// name_uuid: Derives, at compile time, the name-based (RFC 4122 version 5) UUID for the specified
+///            type path (or name) within the indicated namespace (see name_uuid()).
+///
+/// Parameters:
+/// * $namespace (expr): The u128 UUID of the namespace within which the type path is unique,
+///                      typically the UUID of the library that defines the type.
+/// * $type_path (path): The path of the type being identified, e.g. ui_element::UIElement, from
+///                      which the name is derived verbatim (e.g. "ui_element::UIElement").
+/// * $name (literal): Alternatively, the name string from which to derive the UUID.
+///
+/// Notes:
+/// * The module within which name_uuid! is invoked must import name_uuid().
+/// * Once a type's UUID has been derived, it must never change, so neither may the type path (or
+///   name) specified; renaming or moving the type must not update it.
+///
+/// # Example
+///
+/// ```
+/// use rdh::name_uuid;
+/// use rdh::_infrastructure::uuid::name_uuid;
+///
+/// const VEHICLES_UUID: u128 = 0x6ba7b810_9dad_11d1_80b4_00c04fd430c8;
+/// const CAR_TYPE_UUID: u128 = name_uuid!(VEHICLES_UUID, vehicles::car::Car);
+///
+/// assert_eq!(CAR_TYPE_UUID, name_uuid!(VEHICLES_UUID, "vehicles::car::Car"));
+/// assert_eq!((CAR_TYPE_UUID >> 76) & 0xf, 5);
+/// ```
+///
+#[macro_export]
+macro_rules! name_uuid
+{
+    ($namespace: expr, $name: literal) =>
+    {
+        {
+            const NAME_UUID: u128 = name_uuid($namespace, $name);
+            NAME_UUID
+        }
+    };
+    ($namespace: expr, $type_path_head: ident $( :: $type_path_tail: ident )*) =>
+    {
+        {
+            const NAME_UUID: u128 = name_uuid($namespace,
+                                              concat!(stringify!($type_path_head)
+                                                      $( , "::", stringify!($type_path_tail) )*));
+            NAME_UUID
+        }
+    };
+}
+
+///
+/// uuid: Parses the specified hyphenated hex UUID literal at compile time (see parse_uuid()).
+///
+/// Parameters:
+/// * $text (literal): The UUID's text, e.g. "1f210cc2-799f-4bd0-9669-ea80610818b8".
+///
+/// Panics:
+/// * At compile time, if the literal isn't a valid hyphenated hex UUID.
+///
+/// Notes:
+/// * The module within which uuid! is invoked must import parse_uuid().
+///
+/// # Example
+///
+/// ```
+/// use rdh::uuid;
+/// use rdh::_infrastructure::uuid::parse_uuid;
+///
+/// const RDH_EXTENSION_UUID: u128 = uuid!("1f210cc2-799f-4bd0-9669-ea80610818b8");
+///
+/// assert_eq!(RDH_EXTENSION_UUID, 41377672462483076378035019486899738808);
+/// ```
+///
+#[macro_export]
+macro_rules! uuid
+{
+    ($text: literal) =>
+    {
+        {
+            const UUID: u128 = match parse_uuid($text)
+            {
+                Ok(uuid) => uuid,
+                Err(_) => panic!("uuid!: Malformed UUID literal (expected 8-4-4-4-12 hyphenated \
+                                  hex digits)!")
+            };
+            UUID
+        }
+    };
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Internal (i.e. public in crate) constants and functions
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+//
+// UUID_TEXT_LENGTH: The length of a UUID's hyphenated hex text, e.g.
+//                   "1f210cc2-799f-4bd0-9669-ea80610818b8".
+//
+pub(crate) const UUID_TEXT_LENGTH: usize = 36;
+
+//
+// uuid_string: Expresses the specified UUID as lowercase hyphenated hex (8-4-4-4-12 digits).
+//
+pub(crate) fn uuid_string(uuid: u128) -> String
+{
+    format!("{:08x}-{:04x}-{:04x}-{:04x}-{:012x}", uuid >> 96, (uuid >> 80) & 0xffff,
+            (uuid >> 64) & 0xffff, (uuid >> 48) & 0xffff, uuid & 0xffff_ffff_ffff)
+}
+
+//
+// write_uuid: Writes the specified UUID as lowercase hyphenated hex (see uuid_string()).
+//
+pub(crate) fn write_uuid(formatter: &mut fmt::Formatter<'_>, uuid: u128) -> fmt::Result
+{
+    formatter.write_str(&uuid_string(uuid))
+}
+
+//
+// parse_uuid_at: Parses the hyphenated hex UUID (of either case) occupying the UUID_TEXT_LENGTH
+//                bytes of the specified text that begin at the indicated offset.
+//
+// Expresses:
+// * If successful, the UUID.
+// * Otherwise, ParseIdentifierError::InvalidCharacter, indicating the offending byte's offset
+//   within the whole text.
+//
+// Notes:
+// * The caller must ensure that the text contains at least offset + UUID_TEXT_LENGTH bytes.
+//
+pub(crate) const fn parse_uuid_at(text: &[u8], offset: usize) -> Result<u128, ParseIdentifierError>
+{
+    let mut uuid: u128 = 0;
+    let mut index = 0;
+    while index < UUID_TEXT_LENGTH
+    {
+        let character = text[offset + index];
+        if index == 8 || index == 13 || index == 18 || index == 23
+        {
+            if character != b'-'
+            {
+                return Err(ParseIdentifierError::InvalidCharacter { position: offset + index });
+            }
+        }
+        else
+        {
+            let digit = match character
+            {
+                b'0'..=b'9' => character - b'0',
+                b'a'..=b'f' => character - b'a' + 10,
+                b'A'..=b'F' => character - b'A' + 10,
+                _ => return Err(ParseIdentifierError::InvalidCharacter { position: offset + index })
+            };
+            uuid = (uuid << 4) | digit as u128;
+        }
+        index += 1;
+    }
+    Ok(uuid)
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Private functions
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+//
+// sha1: Computes the SHA-1 digest of the specified namespace bytes followed by the specified name
+//       bytes (per FIPS 180-4).
+//
+const fn sha1(namespace: [u8; 16], name: &[u8]) -> [u8; 20]
+{
+    let message_length = namespace.len() + name.len();
+
+    // The message is padded with a 1 bit, then 0 bits, then its 64-bit length in bits, to a
+    // multiple of 64 bytes.
+    let padded_length = (message_length + 8) / 64 * 64 + 64;
+    let mut state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    let mut block_start = 0;
+    while block_start < padded_length
+    {
+        let mut schedule = [0u32; 80];
+        let mut index = 0;
+        while index < 16
+        {
+            let mut word = 0u32;
+            let mut byte_index = 0;
+            while byte_index < 4
+            {
+                let position = block_start + 4 * index + byte_index;
+                word = (word << 8) | padded_byte(&namespace, name, position, padded_length) as u32;
+                byte_index += 1;
+            }
+            schedule[index] = word;
+            index += 1;
+        }
+        while index < 80
+        {
+            schedule[index] = (schedule[index - 3] ^ schedule[index - 8] ^ schedule[index - 14] ^
+                               schedule[index - 16]).rotate_left(1);
+            index += 1;
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        index = 0;
+        while index < 80
+        {
+            let (f, k) = match index
+            {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6)
+            };
+            let temp = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k)
+                        .wrapping_add(schedule[index]);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+            index += 1;
+        }
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+        block_start += 64;
+    }
+
+    let mut digest = [0u8; 20];
+    let mut index = 0;
+    while index < 5
+    {
+        let word_bytes = state[index].to_be_bytes();
+        let mut byte_index = 0;
+        while byte_index < 4
+        {
+            digest[4 * index + byte_index] = word_bytes[byte_index];
+            byte_index += 1;
+        }
+        index += 1;
+    }
+    digest
+}
+
+//
+// padded_byte: Retrieves the byte at the specified position within the padded SHA-1 message
+//              comprising the specified namespace bytes followed by the specified name bytes.
+//
+const fn padded_byte(namespace: &[u8; 16], name: &[u8], position: usize, padded_length: usize) -> u8
+{
+    let message_length = namespace.len() + name.len();
+    if position < namespace.len()
+    {
+        namespace[position]
+    }
+    else if position < message_length
+    {
+        name[position - namespace.len()]
+    }
+    else if position == message_length
+    {
+        0x80
+    }
+    else if position >= padded_length - 8
+    {
+        let bit_length = (message_length as u64) * 8;
+        (bit_length >> (8 * (padded_length - 1 - position))) as u8
+    }
+    else
+    {
+        0
+    }
+}
+
+// *** Minutiae ***
+
+use std::fmt;
+use crate::_infrastructure::thaumaturgy::ParseIdentifierError;
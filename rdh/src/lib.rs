@@ -30,10 +30,28 @@ pub mod _infrastructure;
 // Public initialization functions
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
+provision_library!
+{
+    ///
+    /// LIBRARY: The core RDH library's place within the library dependency graph, to be listed
+    ///          among the parents of each RDH library that directly encompasses its types.
+    ///
+    pub static LIBRARY: "rdh" (RDH_LIBRARY_UUID)
+      version RDH_VERSION, requires rdh RDH_VERSION,
+      extends []
+    {
+        _hierarchy::construct
+    }
+}
+
 ///
 /// init: Initializes the core RDH library.
 ///
 /// Project main() function must invoke the init() function for the top-level RDH library.
+///
+/// Notes:
+/// * Idempotent: the core library's types are registered only the first time this function (or
+///   the init() function of any library that depends upon it) is invoked.
 /// 
 pub fn init()
 {
@@ -45,12 +63,11 @@ pub fn init()
     //   * Alternative solutions are being considered at this time.
     //     * For further information, see Issue #5 in Knowledge Base.md.
     //
-    // * The init() function for all RDH libraries must invoke init() from their parent library.
+    // * The init() function for all RDH libraries must initialize their own LIBRARY, which
+    //   initializes its parent libraries first.
     //
 
-    // Register framework types defined within the core library.
-    TypeRegistry::register_type::<dyn IConstruct>(ICONSTRUCT_TYPE_UUID, RDH_LIBRARY_UUID);
-    TypeRegistry::register_type::<Construct>(CONSTRUCT_TYPE_UUID, RDH_LIBRARY_UUID);
+    LIBRARY.init();
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -66,5 +83,4 @@ const RDH_LIBRARY_UUID: u128 = 88009063541924297814935272469493149666;
 
 // *** Minutiae ***
 
-use crate::_hierarchy::construct::{ Construct, CONSTRUCT_TYPE_UUID, IConstruct, ICONSTRUCT_TYPE_UUID };
-use crate::_infrastructure::thaumaturgy::TypeRegistry;
+use crate::_infrastructure::initialization::{ Library, RDH_VERSION };
@@ -138,11 +138,8 @@ pub fn dispatch_iconstruct_by_concrete_type<T>(iconstruct: &mut T)
 ///   (Construct) type is unknown at compile time, through functions that expect a trait object of
 ///   a different type (or a concrete instance that can be coerced into the that type).
 ///
-/// Generic parameters:
-/// * T (IConstruct + ?Sized): Represents all (IConstructs).
-///
 /// Parameters:
-/// * iconstruct (&mut T): The reference to the (IConstruct) object to process.
+/// * iconstruct (&mut dyn IConstruct): The reference to the (IConstruct) object to process.
 ///
 /// Panics:
 /// * When downcasting fails after an implemented interface was positively identified.
@@ -185,24 +182,16 @@ pub fn dispatch_iconstruct_by_concrete_type<T>(iconstruct: &mut T)
 ///       UIElement, casting to UIElement (or at least &mut dyn IUIElement) is required before new
 ///       functions contributed by IUIElement can be invoked.
 ///
-/// * Note that because the generic type is constrained to IConstruct, only methods defined in
-///   IConstruct are available before the object is cast to another type.
-///   * This includes methods implemented for dyn IConstruct-- if it's not defined in IConstruct,
-///     it can't be invoked without casting.
-///   * Unfortunately, because a type so constrained is unsized, there's no way to cast to &mut dyn
-///     IConstruct without casting it to a concrete type first.
-///     * This makes invoking downcasts without knowing the concrete type impossible, but these
-///       examples are intended to demonstrate how to proceed when the concrete type is not known
-///       anyway.
-///       * The "impossible" is made possible by discovering the concrete type at runtime.
-///       * This unfortunately does not allow the caller to retain a references to the concrete
-///         type, however.
-///   * Because methods from traits implemented for &mut dyn IConstruct aren't available, this
-///     function instead directly invokes the Transmutation struct responsible for downcasting to
-///     IUIElement.
+/// * Unlike dispatch_iconstruct_by_concrete_type, this function accepts a trait object rather
+///   than a generic parameter.
+///   * Downcasting methods are implemented for dyn IConstruct (of any lifetime), so they may be
+///     invoked directly, e.g. iconstruct.as_iui_element_mut().
+///   * Were this function generic, those methods would be unavailable, as Rust offers no way to
+///     indicate that a generic parameter is a trait object (see Issue #3 in "Knowledge Base.md").
+///   * Concrete instances coerce to &mut dyn IConstruct automatically, whereas generic callers may
+///     call .as_iconstruct_mut() (see Issue #4 in "Knowledge Base.md").
 ///
-pub fn dispatch_iconstruct_by_implemented_interface<T>(iconstruct: &mut T)
-    where T: IConstruct + ?Sized
+pub fn dispatch_iconstruct_by_implemented_interface(iconstruct: &mut dyn IConstruct)
 {
     //
     // Implementation notes:
@@ -221,8 +210,8 @@ pub fn dispatch_iconstruct_by_implemented_interface<T>(iconstruct: &mut T)
 
     if iconstruct.implements(TypeRegistry::type_identifier_of::<dyn IUIElement>())
     {
-        process_any_iui_element(IUIElementDowncaster::as_iui_element_mut(iconstruct).expect(
-          "dispatch_iconstruct_by_implemented_interface<T>(): Unable to cast (IConstruct) to \
+        process_any_iui_element(iconstruct.as_iui_element_mut().expect(
+          "dispatch_iconstruct_by_implemented_interface(): Unable to cast (IConstruct) to \
            IUIElement when concrete type is known to implement it!"));
     }
     // There's no need to check to see if iconstruct implements IConstruct-- *every* RDH type does.
@@ -340,4 +329,4 @@ fn process_any_iconstruct<T>(construct: &mut T)
 
 use rdh::_hierarchy::construct::{ Construct, ConstructVirtuals, IConstruct };
 use rdh::_infrastructure::thaumaturgy::{ TypeRegistry, Necromances };
-use crate::_hierarchy::ui_element::{ IUIElement, UIElement, IUIElementDowncaster };
+use crate::_hierarchy::ui_element::{ DowncastsToIUIElement, IUIElement, UIElement };
@@ -20,4 +20,5 @@
 //
 
 pub mod checkbox;
+pub mod property;
 pub mod ui_element;
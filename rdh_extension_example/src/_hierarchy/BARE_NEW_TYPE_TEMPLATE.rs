@@ -121,7 +121,7 @@ impl ConstructVirtuals for NewType
 // Implement upcasting and downcasting to INewInterface and between interfaces NewType implements.
 provision_transmutation!
 {
-    for NewType: INewInterface + [IConstruct]
+    for NewType: INewInterface + [IConstruct] extends Construct
     {
         add Fn[as_inew_interface, as_inew_interface_mut] to
         (
@@ -138,14 +138,24 @@ define_instances! { NewTypeInstances for NewType : Fn[new_type, new_type_mut] ->
 define_instances! { ConstructInstances for NewType : Fn[construct, construct_mut] ->
                     Construct (construct) }
 
+// Register INewInterface, NewType, and this library's INewInterface downcast handler with the
+// library that lists this module.
+register_types!
+{
+    types [dyn INewInterface = INEW_INTERFACE_TYPE_UUID, NewType = NEW_TYPE_TYPE_UUID]
+    handlers [INewInterfaceDowncaster: DowncastsRdhExtensionTypesToINewInterface]
+}
+
 // *** Internal (i.e. public in crate) constants ***
 
 //
 // INEW_INTERFACE_TYPE_UUID: The unique identifier for the INewInterface type.
 // NEW_TYPE_TYPE_UUID: The unique identifier for the NewType type.
 //
-pub(crate) const INEW_INTERFACE_TYPE_UUID: u128 = -1;
-pub(crate) const NEW_TYPE_TYPE_UUID: u128 = -1;
+pub(crate) const INEW_INTERFACE_TYPE_UUID: u128 =
+  name_uuid!(crate::RDH_EXTENSION_UUID, new_type::INewInterface);
+pub(crate) const NEW_TYPE_TYPE_UUID: u128 =
+  name_uuid!(crate::RDH_EXTENSION_UUID, new_type::NewType);
 
 // *** Minutiae ***
 
@@ -157,9 +167,17 @@ use rdh::_infrastructure::thaumaturgy::ConcreteDivinator;
 use rdh::define_instances;
 use rdh::extend_downcasting;
 use rdh::impl_concrete_divinator;
+use rdh::name_uuid;
 use rdh::provision_transmutation;
 use rdh::provision_upcasting;
+use rdh::register_types;
 use std::collections::HashMap;
-use std::sync::{ Once, RwLock };
-use rdh::_infrastructure::thaumaturgy::{ Divinator, LibraryIdentifier, Necromances, TypeIdentifier,
-                                         TypeRegistry };
+use std::rc::Rc;
+use std::sync::{ Arc, OnceLock };
+use rdh::_infrastructure::thaumaturgy::{ CastError, CastFailure, CastTarget, DerivedCasts,
+                                         Divinator, Downcastable, Encompasses, FreezableLock,
+                                         LibraryIdentifier, Lineage, Necromances, ParentAccessors,
+                                         Registered, RegistryError, RegistryLock, RegistryScope,
+                                         Transmuter, TypeIdentifier, TypeKind, TypeRegistry };
+use rdh::_infrastructure::uuid::name_uuid;
+use std::any::TypeId;
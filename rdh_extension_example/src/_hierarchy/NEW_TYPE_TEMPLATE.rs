@@ -260,13 +260,15 @@ impl ConstructVirtuals for NewType
 //       Add the public interfaces for all intermediate types, each followed by a space,
 //        immediately prior to IConstruct below, e.g.:
 //         ... + [ IUtilityVehicle IVehicle IConstruct ]
+//       Replace Construct following "extends" with the type NewType directly encompasses, e.g.:
+//         ... + [ IUtilityVehicle IVehicle IConstruct ] extends UtilityVehicle
 //
 // [ ] DONE
 // ************************************************************************************************
 // Implement upcasting and downcasting to INewInterface and between interfaces NewType implements.
 provision_transmutation!
 {
-    for NewType: INewInterface + [IConstruct]
+    for NewType: INewInterface + [IConstruct] extends Construct
     {
         add Fn[as_inew_interface, as_inew_interface_mut] to
         (
@@ -304,6 +306,24 @@ define_instances! { NewTypeInstances for NewType : Fn[new_type, new_type_mut] ->
 define_instances! { ConstructInstances for NewType : Fn[construct, construct_mut] ->
                     Construct (construct) }
 
+// ************************************************************************************************
+// TODO: Rename DowncastsRdhExtensionTypesToINewInterface as within provision_transmutation! above.
+//       When NewType encompasses, directly or indirectly, a type other than Construct (whether
+//        defined within the current library or a previous one), NewType is registered with the
+//        downcasting struct of each such type's public interface automatically (as listed within
+//        provision_transmutation! above), so no changes to the modules of any of those ancestor
+//        types are required.
+//
+// [ ] DONE
+// ************************************************************************************************
+// Register INewInterface, NewType, and this library's INewInterface downcast handler with the
+// library that lists this module.
+register_types!
+{
+    types [dyn INewInterface = INEW_INTERFACE_TYPE_UUID, NewType = NEW_TYPE_TYPE_UUID]
+    handlers [INewInterfaceDowncaster: DowncastsRdhExtensionTypesToINewInterface]
+}
+
 // *** Internal (i.e. public in crate) constants ***
 
 //
@@ -311,19 +331,20 @@ define_instances! { ConstructInstances for NewType : Fn[construct, construct_mut
 // NEW_TYPE_TYPE_UUID: The unique identifier for the NewType type.
 //
 // ************************************************************************************************
-// TODO: Populate UUIDs below with random u128 values, e.g. 246318330869446075964898337533927694308
-//        Failing to do so will result in intended compiler errors.
-//       As these are simply UUIDs (AKA GUIDs) in decimal format, the following online tools may be
-//       helpful:
-//       * Generate random GUIDs: Online GUID / UUID Generator
-//          https://guidgenerator.com/online-guid-generator.aspx
-//       * Convert GUIDs to u128: GUID Converter
-//          https://toolslick.com/conversion/data/guid
+// TODO: Replace the type paths below with those of the new types, e.g. pickup_truck::IPickupTruck
+//        and pickup_truck::PickupTruck, from which name_uuid! derives their UUIDs within the
+//        current library's namespace (replace RDH_EXTENSION_UUID with its UUID constant).
+//       Once released, these UUIDs must never change, so never update these paths afterward
+//        (e.g. when renaming or moving the types).
+//       Alternatively, paste randomly generated UUIDs via uuid!, e.g.
+//        uuid!("0fa46ec9-27fc-a74b-a732-0ea673cd5d68") (and import rdh::uuid and parse_uuid()).
 //
 // [ ] DONE
 // ************************************************************************************************
-pub(crate) const INEW_INTERFACE_TYPE_UUID: u128 = -1;
-pub(crate) const NEW_TYPE_TYPE_UUID: u128 = -1;
+pub(crate) const INEW_INTERFACE_TYPE_UUID: u128 =
+  name_uuid!(crate::RDH_EXTENSION_UUID, new_type::INewInterface);
+pub(crate) const NEW_TYPE_TYPE_UUID: u128 =
+  name_uuid!(crate::RDH_EXTENSION_UUID, new_type::NewType);
 
 // *** Minutiae ***
 
@@ -341,86 +362,28 @@ use rdh::_infrastructure::thaumaturgy::ConcreteDivinator;
 use rdh::define_instances;
 use rdh::extend_downcasting;
 use rdh::impl_concrete_divinator;
+use rdh::name_uuid;
 use rdh::provision_transmutation;
 use rdh::provision_upcasting;
+use rdh::register_types;
 use std::collections::HashMap;
-use std::sync::{ Once, RwLock };
-use rdh::_infrastructure::thaumaturgy::{ Divinator, LibraryIdentifier, Necromances, TypeIdentifier,
-                                         TypeRegistry };
+use std::rc::Rc;
+use std::sync::{ Arc, OnceLock };
+use rdh::_infrastructure::thaumaturgy::{ CastError, CastFailure, CastTarget, DerivedCasts,
+                                         Divinator, Downcastable, Encompasses, FreezableLock,
+                                         LibraryIdentifier, Lineage, Necromances, ParentAccessors,
+                                         Registered, RegistryError, RegistryLock, RegistryScope,
+                                         Transmuter, TypeIdentifier, TypeKind, TypeRegistry };
+use rdh::_infrastructure::uuid::name_uuid;
+use std::any::TypeId;
 
 // ************************************************************************************************
 // TODO: Update the current library's lib module.
-//        Read carefully! This part is more involved than the rest.
-//        If you have not read all the provided documentation, you should do so before proceeding.
-//
-//       NOTE: Construct is explicitly excluded in many places below because it isn't possible to
-//              downcast to IConstruct.
-//
-//       1) Create or update downcasting extension macro invocations:
-//          a) When NewType directly encompasses a type (other than Construct) defined in a
-//             previous library:
-//             * Invoke the macro that will enable downcasts to that type when NewType is the
-//               underlying concrete type, e.g.:
-//                 extend_downcasting!
-//                 {
-//                     for IEncompassedType
-//                     {
-//                         add Fn[as_iencompassed_type, as_iencompassed_type_mut] to
-//                          DowncastsRdhExtensionTypesToIEncompassedType:
-//                           DowncastsLibraryTypesToIEncompassedType include [NewType]
-//                     }
-//                 }
-//             * In the above, be sure to 1) swap out IEncompassedType, etc., for the target type,
-//                and 2) update DowncastsRdhExtensionTypesToIEncompassedType to reflect the naming
-//                convention established within the provision_transmutation! invocation above.
-//             * Repeat this for any types back to, but not including, Construct.
-//
-//          b) The next TODO covers situations where the TristateCheckbox encompasses a type
-//              defined within the current library that in turn encompasses a type (other than
-//              Construct) defined in a previous library.
-//
-//       2) Update the init() function:
-//          a) Register NewType and INewInterface within the TypeRegistry, using the library's UUID
-//             as defined within the lib module, e.g.:
-//               TypeRegistry::register_type::<NewType>(NEW_TYPE_TYPE_UUID, RDH_EXTENSION_UUID);
-//               TypeRegistry::register_type::<dyn INewInterface>(INEW_INTERFACE_TYPE_UUID,
-//                                                                RDH_EXTENSION_UUID);
-//
-//          b) Create and register downcast handlers for NewType, (updating
-//              DowncastsRdhExtensionTypesToINewInterface to reflect the naming convention
-//              established within the provision_transmutation! invocation above), e.g:
-//                INewInterfaceDowncaster::register_handler(
-//                  TypeRegistry::library_identifier_of::<NewType>(),
-//                  Box::new(DowncastsRdhExtensionTypesToINewInterface{}));
-//
-//          c) When NewType encompasses, directly or indirectly, a type (other than Construct)
-//             defined in a previous library, register a new downcast handler per the macro created
-//             earilier (updating names to match macro invocation), e.g.:
-//               IEncompassedTypeDowncaster::register_handler(
-//                 TypeRegistry::library_identifier_of::<NewType>(),
-//                 Box::new(DowncastsRdhExtensionTypesToIEncompassedType{}));
-//             * Repeat this for any types back to, but not including, Construct.
 //
-// [ ] DONE
-// ************************************************************************************************
-
-// ************************************************************************************************
-// TODO: Unless NewType directly encompasses Construct, update the provision_transmutation! call
-//       within all ancestor types defined within the current library.
-//       1) When NewType encompasses a type defined within the current library that in turn
-//           directly encompasses Construct, within that type's module:
-//          Add a list of types to include when looking for an (IConstruct's) concrete type,
-//           populating it only with NewType, e.g.:
-//            ...
-//            DowncastsRdhExtensionTypesToIParentType:
-//              DowncastsLibraryTypesToIParentType include [NewType]
-//            ...
-//       2) Otherwise, within that type's module, add NewType to the end of the existing bracketed
-//          type list, separated by a space, e.g.:
-//            ...
-//            DowncastsRdhExtensionTypesToIAncestorType:
-//              DowncastsLibraryTypesToIAncestorType include [ParentType NewType]
-//            ...
+//       Add this module to the list of modules within the library's provision_library! invocation,
+//        following the modules of any types NewType encompasses, e.g.:
+//         _hierarchy::vehicle, _hierarchy::utility_vehicle, _hierarchy::new_type
+//        Until then, the compiler reports this module's UUID constants as never used.
 //
 // [ ] DONE
 // ************************************************************************************************
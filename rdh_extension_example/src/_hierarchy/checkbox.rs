@@ -146,7 +146,7 @@ impl ConstructVirtuals for Checkbox
 // Implement upcasting and downcasting to ICheckbox and between interfaces Checkbox implements.
 provision_transmutation!
 {
-    for Checkbox: ICheckbox + [IUIElement IConstruct]
+    for Checkbox: ICheckbox + [IUIElement IConstruct] extends UIElement
     {
         add Fn[as_icheckbox, as_icheckbox_mut] to
         (
@@ -165,6 +165,14 @@ define_instances! { UIElementInstances for Checkbox : Fn[ui_element, ui_element_
 define_instances! { ConstructInstances for Checkbox : Fn[construct, construct_mut] ->
                       Construct (ui_element {.construct} {.construct_mut}) }
 
+// Register ICheckbox, Checkbox, and this library's ICheckbox downcast handler with the library
+// that lists this module, and make Checkbox downcastable to IUIElement.
+register_types!
+{
+    types [dyn ICheckbox = ICHECKBOX_TYPE_UUID, Checkbox = CHECKBOX_TYPE_UUID]
+    handlers [ICheckboxDowncaster: DowncastsRdhExtensionTypesToICheckbox]
+}
+
 // *** Internal (i.e. public in crate) constants ***
 
 //
@@ -188,7 +196,13 @@ use rdh::extend_downcasting;
 use rdh::impl_concrete_divinator;
 use rdh::provision_transmutation;
 use rdh::provision_upcasting;
+use rdh::register_types;
 use std::collections::HashMap;
-use std::sync::{ Once, RwLock };
-use rdh::_infrastructure::thaumaturgy::{ Divinator, LibraryIdentifier, Necromances, TypeIdentifier,
-                                         TypeRegistry };
+use std::rc::Rc;
+use std::sync::{ Arc, OnceLock };
+use rdh::_infrastructure::thaumaturgy::{ CastError, CastFailure, CastTarget, DerivedCasts,
+                                         Divinator, Downcastable, Encompasses, FreezableLock,
+                                         LibraryIdentifier, Lineage, Necromances, ParentAccessors,
+                                         Registered, RegistryError, RegistryLock, RegistryScope,
+                                         Transmuter, TypeIdentifier, TypeKind, TypeRegistry };
+use std::any::TypeId;
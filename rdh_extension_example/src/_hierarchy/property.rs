@@ -0,0 +1,251 @@
+// ************************************************************************************************
+// Copyright 2023 Gene DeClark and Contributors within this file's version control history
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+// ************************************************************************************************
+
+//!
+//! Within comments throughout the codebase, type names in parentheses indicate an "is a"
+//!  relationship, i.e. that type and any type that encompasses that type.
+//! For example:
+//! * (IProperty) means an IProperty trait object or any trait object encompassing IProperty.
+//! * (Property) means any instantiation of the Property<T> struct or any concrete type that
+//!   implements IProperty.
+//! Whereas:
+//! * IProperty (no parentheses) means the literal IProperty trait or its trait objects.
+//! * Property<T> (no parentheses) means the literal Property<T> struct or its instances.
+//!
+
+//!
+//! property.rs
+//!
+//! IProperty (Property<T>)
+//!   The public interface and generic struct that serve as an example of generic RDH types.
+//!
+//! THIS IS A WORK IN PROGRESS. All current functionality is placeholder only and *will* change.
+//!
+//! Notes:
+//! * Each instantiation of Property<T> (e.g. Property<bool>) is registered as a distinct type with
+//!   its own UUID, and also as a member of the PropertyFamily generic family.
+//!   * is(TypeRegistry::type_identifier_of::<Property<bool>>()) is true only for Property<bool>.
+//!   * is(TypeRegistry::family_identifier_of::<PropertyFamily>()) is true for any registered
+//!     Property<T>.
+//!
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Property<T> struct
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Property: The implementation of (Property) functionality.
+///
+pub struct Property<T>
+{
+    //
+    // construct: Stores this (Property's) Construct instance.
+    //
+    construct: Construct,
+
+    //
+    // value: Stores this Property's value.
+    //
+    value: T
+}
+impl<T> Property<T>
+{
+    ///
+    /// new: Creates a new instance of the Property<T> struct.
+    ///
+    /// Parameters:
+    /// * value (T): The initial value of the Property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rdh_extension_example::_hierarchy::property::Property;
+    ///
+    /// let property = Property::new(true);
+    /// ```
+    ///
+    pub fn new(value: T) -> Property<T>
+    {
+        Property
+        {
+            construct: Construct::new(),
+            value
+        }
+    }
+
+    // *** Properties ***
+
+    ///
+    /// value: Gets and sets this Property's value via get_value and set_value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdh_extension_example::_hierarchy::property::Property;
+    ///
+    /// let mut property_mut = Property::new(1);
+    /// property_mut.set_value(2);
+    /// println!("{}", property_mut.get_value());
+    /// ```
+    ///
+    pub fn get_value(&self) -> &T
+    {
+        &(self.value)
+    }
+    pub fn set_value(&mut self, value: T)
+    {
+        self.value = value;
+    }
+}
+
+///
+/// PropertyFamily: The marker type that represents the generic family of all Property<T>
+///                 instantiations within the TypeRegistry.
+///
+pub struct PropertyFamily;
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// IProperty public interface trait and component / supplemental traits
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// IProperty: The interface implemented by all (Properties).
+//              * Non-virtual functions are directly declared and implemented below.
+//              * Virtual functions are declared within the PropertyVirtuals trait.
+//                Their base implementation is below-- encompassing types override as needed.
+//              * Casting functionality provided by additional component and supplemental traits.
+///
+pub trait IProperty : PropertyVirtuals + UpcastsToIProperty +
+                      IConstruct
+{
+}
+pub trait PropertyVirtuals
+{
+    ///
+    /// value_type_name: Retrieves the name of the type of value held by this (Property).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdh_extension_example::_hierarchy::property::{ Property, PropertyVirtuals };
+    ///
+    /// let property = Property::new(true);
+    /// println!("{}", property.value_type_name());
+    /// ```
+    ///
+    fn value_type_name(&self) -> &'static str;
+}
+impl<T> IProperty for T where T: PropertyVirtuals +
+                                 ConstructVirtuals + ConstructInstances +
+                                 ConcreteDivinator
+{
+}
+impl<T> PropertyVirtuals for Property<T>
+{
+    //
+    // value_type_name: Retrieves the name of the type of value held by this (Property).
+    //
+    fn value_type_name(&self) -> &'static str
+    {
+        any::type_name::<T>()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Inherited virtual function overrides
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+//
+// IConstruct overrides.
+//
+impl<T> ConstructVirtuals for Property<T> where T: 'static
+{
+    ///
+    /// on_click: Handles click events for this (Property), according to its concrete type.
+    ///
+    /// THIS METHOD IS PLACEHOLDER ONLY AND WILL BE REMOVED AT A LATER DATE.
+    ///
+    fn on_click(&mut self)
+    {
+        // Carry out base type actions.
+        self.construct_mut().on_click();
+
+        // Perform additional work.
+        println!("on_click handled by Property<{}> implementation!", any::type_name::<T>());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Infrastructure
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// *** Macro Invocations ***
+
+// Implement upcasting to IProperty and between interfaces Property<T> implements.
+provision_transmutation!
+{
+    for<T> Property<T>: IProperty + [IConstruct] extends Construct
+    {
+        add Fn[as_iproperty, as_iproperty_mut] to UpcastsToIProperty
+    }
+}
+
+// Provide access to the encompassed Construct instance.
+define_instances! { ConstructInstances for<T> Property<T> : Fn[construct, construct_mut] ->
+                      Construct (construct) }
+
+// Register IProperty, the Property<T> family, and each of its instantiations used by this library
+// with the library that lists this module.
+register_types!
+{
+    types [dyn IProperty = IPROPERTY_TYPE_UUID]
+    families [PropertyFamily = PROPERTY_FAMILY_UUID]
+    members
+    [
+        PropertyFamily: Property<bool> = PROPERTY_BOOL_TYPE_UUID,
+        PropertyFamily: Property<String> = PROPERTY_STRING_TYPE_UUID
+    ]
+}
+
+// *** Internal (i.e. public in crate) constants ***
+
+//
+// IPROPERTY_TYPE_UUID: The unique identifier for the IProperty type.
+// PROPERTY_FAMILY_UUID: The unique identifier for the Property<T> generic family.
+// PROPERTY_BOOL_TYPE_UUID: The unique identifier for the Property<bool> type.
+// PROPERTY_STRING_TYPE_UUID: The unique identifier for the Property<String> type.
+//
+pub(crate) const IPROPERTY_TYPE_UUID: u128 = 43756682183584169942175299904510504414;
+pub(crate) const PROPERTY_FAMILY_UUID: u128 = 86157536459032462056507995944814102077;
+pub(crate) const PROPERTY_BOOL_TYPE_UUID: u128 = 86278656256766546894230692424726747909;
+pub(crate) const PROPERTY_STRING_TYPE_UUID: u128 = 293384473180908580373889959043585276323;
+
+// *** Minutiae ***
+
+// Traits and types
+use rdh::_hierarchy::construct::{ Construct, ConstructInstances, ConstructVirtuals, IConstruct };
+use rdh::_infrastructure::thaumaturgy::ConcreteDivinator;
+use std::any;
+
+// Macros and dependencies
+use rdh::define_instances;
+use rdh::impl_concrete_divinator;
+use rdh::provision_transmutation;
+use rdh::provision_upcasting;
+use rdh::register_types;
+use rdh::_infrastructure::thaumaturgy::{ DerivedCasts, Divinator, Encompasses, LibraryIdentifier,
+                                         Lineage, ParentAccessors, Registered, RegistryError,
+                                         TypeIdentifier, TypeKind, TypeRegistry };
+use std::any::TypeId;
+use std::sync::OnceLock;
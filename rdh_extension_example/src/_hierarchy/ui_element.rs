@@ -138,7 +138,7 @@ impl ConstructVirtuals for UIElement
 // Implement upcasting and downcasting to IUIElement and between interfaces UIElement implements.
 provision_transmutation!
 {
-    for UIElement: IUIElement + [IConstruct]
+    for UIElement: IUIElement + [IConstruct] extends Construct
     {
         add Fn[as_iui_element, as_iui_element_mut] to
         (
@@ -146,7 +146,6 @@ provision_transmutation!
             DowncastsToIUIElement,
             IUIElementDowncaster,
             DowncastsRdhExtensionTypesToIUIElement: DowncastsLibraryTypesToIUIElement
-              include [Checkbox]
         )
     }
 }
@@ -157,6 +156,14 @@ define_instances! { UIElementInstances for UIElement : Fn[ui_element, ui_element
 define_instances! { ConstructInstances for UIElement : Fn[construct, construct_mut] ->
                       Construct (construct) }
 
+// Register IUIElement, UIElement, and this library's IUIElement downcast handler with the library
+// that lists this module.
+register_types!
+{
+    types [dyn IUIElement = IUI_ELEMENT_TYPE_UUID, UIElement = UI_ELEMENT_TYPE_UUID]
+    handlers [IUIElementDowncaster: DowncastsRdhExtensionTypesToIUIElement]
+}
+
 // *** Internal (i.e. public in crate) constants ***
 
 //
@@ -171,7 +178,6 @@ pub(crate) const UI_ELEMENT_TYPE_UUID: u128 = 2161761007873234949512912432941437
 // Traits and types
 use rdh::_hierarchy::construct::{ Construct, ConstructInstances, ConstructVirtuals, IConstruct };
 use rdh::_infrastructure::thaumaturgy::ConcreteDivinator;
-use crate::_hierarchy::checkbox::Checkbox;
 
 // Macros and dependencies
 use rdh::define_instances;
@@ -179,7 +185,13 @@ use rdh::extend_downcasting;
 use rdh::impl_concrete_divinator;
 use rdh::provision_transmutation;
 use rdh::provision_upcasting;
+use rdh::register_types;
 use std::collections::HashMap;
-use std::sync::{ Once, RwLock };
-use rdh::_infrastructure::thaumaturgy::{ Divinator, LibraryIdentifier, Necromances, TypeIdentifier,
-                                         TypeRegistry };
+use std::rc::Rc;
+use std::sync::{ Arc, OnceLock };
+use rdh::_infrastructure::thaumaturgy::{ CastError, CastFailure, CastTarget, DerivedCasts,
+                                         Divinator, Downcastable, Encompasses, FreezableLock,
+                                         LibraryIdentifier, Lineage, Necromances, ParentAccessors,
+                                         Registered, RegistryError, RegistryLock, RegistryScope,
+                                         Transmuter, TypeIdentifier, TypeKind, TypeRegistry };
+use std::any::TypeId;
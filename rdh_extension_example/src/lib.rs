@@ -36,15 +36,28 @@ pub mod _hierarchy;
 // Public initialization functions
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
+provision_library!
+{
+    ///
+    /// LIBRARY: This example RDH extension library's place within the library dependency graph.
+    ///
+    pub static LIBRARY: "rdh_extension_example" (RDH_EXTENSION_UUID)
+      version env!("CARGO_PKG_VERSION"), requires rdh "0.5.0",
+      extends [rdh::LIBRARY]
+    {
+        _hierarchy::ui_element, _hierarchy::checkbox, _hierarchy::property
+    }
+}
+
 ///
 /// init: Initializes this example RDH extension library and its ancestors.
 ///
 /// Project main() function must invoke the init() function for the top-level RDH library.
+///
+/// Notes:
+/// * Idempotent: each library's types are registered only once, however many libraries that
+///   depend upon it are initialized.
 /// 
-
-//
-// init: Initializes this example RDH extension library and its ancestors.
-//
 pub fn init()
 {
     //
@@ -55,24 +68,11 @@ pub fn init()
     //   * Alternative solutions are being considered at this time.
     //     * For further information, see Issue #5 in Knowledge Base.md.
     //
-    // * The init() function for all RDH libraries must invoke init() from their parent library.
+    // * The init() function for all RDH libraries must initialize their own LIBRARY, which
+    //   initializes its parent libraries first.
     //
 
-    // Initialize the core RDH library.
-    rdh::init();
-
-    // Register new framework types added within this library.
-    TypeRegistry::register_type::<dyn IUIElement>(IUI_ELEMENT_TYPE_UUID, RDH_EXTENSION_UUID);
-    TypeRegistry::register_type::<UIElement>(UI_ELEMENT_TYPE_UUID, RDH_EXTENSION_UUID);
-    TypeRegistry::register_type::<dyn ICheckbox>(ICHECKBOX_TYPE_UUID, RDH_EXTENSION_UUID);
-    TypeRegistry::register_type::<Checkbox>(CHECKBOX_TYPE_UUID, RDH_EXTENSION_UUID);
-
-    // Create and register downcast handlers for existing interfaces when the concrete type is
-    // defined within this library.
-    IUIElementDowncaster::register_handler(TypeRegistry::library_identifier_of::<UIElement>(),
-                                           Box::new(DowncastsRdhExtensionTypesToIUIElement{}));
-    ICheckboxDowncaster::register_handler(TypeRegistry::library_identifier_of::<Checkbox>(),
-                                          Box::new(DowncastsRdhExtensionTypesToICheckbox{}));
+    LIBRARY.init();
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -88,10 +88,5 @@ const RDH_EXTENSION_UUID: u128 = 41377672462483076378035019486899738808;
 
 // *** Minutiae ***
 
-use rdh::_infrastructure::thaumaturgy::TypeRegistry;
-use crate::_hierarchy::checkbox::{ Checkbox, CHECKBOX_TYPE_UUID,
-                                   DowncastsRdhExtensionTypesToICheckbox, ICheckbox,
-                                   ICheckboxDowncaster, ICHECKBOX_TYPE_UUID };
-use crate::_hierarchy::ui_element::{ DowncastsRdhExtensionTypesToIUIElement, IUIElement,
-                                     IUIElementDowncaster, IUI_ELEMENT_TYPE_UUID, UIElement,
-                                     UI_ELEMENT_TYPE_UUID };
+use rdh::_infrastructure::initialization::Library;
+use rdh::provision_library;
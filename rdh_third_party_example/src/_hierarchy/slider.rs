@@ -0,0 +1,220 @@
+// ************************************************************************************************
+// Copyright 2023 Gene DeClark and Contributors within this file's version control history
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+// ************************************************************************************************
+
+//!
+//! Within comments throughout the codebase, type names in parentheses indicate an "is a"
+//!  relationship, i.e. that type and any type that encompasses that type.
+//! For example:
+//! * (ISlider) means an ISlider trait object or any trait object encompassing ISlider.
+//! * (Slider) means the Slider struct or any concrete type that implements ISlider.
+//! Whereas:
+//! * ISlider (no parentheses) means the literal ISlider trait or its trait objects.
+//! * Slider (no parentheses) means the literal Slider struct or its instances.
+//!
+
+//!
+//! slider.rs
+//!
+//! ISlider (Slider)
+//!   The public interface and struct for sliders, an example of a type defined within a
+//!   third-party library that encompasses a type (UIElement) defined within an upstream library.
+//!
+//! Notes:
+//! * Slider is made downcastable to IUIElement through a library downcast handler created via
+//!   extend_downcasting! below and registered with IUIElementDowncaster (see register_types!
+//!   below).
+//!
+//! THIS IS A WORK IN PROGRESS. All current functionality is placeholder only and *will* change.
+//!
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Slider struct
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Slider: The implementation of (Slider) functionality.
+///
+pub struct Slider
+{
+    //
+    // ui_element: Stores this (Slider's) UIElement instance.
+    //
+    ui_element: UIElement
+}
+impl Slider
+{
+    ///
+    /// new: Creates a new instance of the Slider struct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rdh_third_party_example::_hierarchy::slider::Slider;
+    ///
+    /// let slider = Slider::new();
+    /// ```
+    ///
+    pub fn new() -> Slider
+    {
+        Slider
+        {
+            ui_element: UIElement::new()
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// ISlider public interface trait and component / supplemental traits
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// ISlider: The interface implemented by all (Sliders).
+//           * Non-virtual functions are directly declared and implemented below.
+//           * Virtual functions are declared within the SliderVirtuals trait.
+//             Their base implementation is below-- encompassing types override as needed.
+//           * Casting functionality provided by additional component and supplemental traits.
+///
+pub trait ISlider : SliderVirtuals + UpcastsToISlider +
+                    IUIElement + IConstruct
+{
+}
+pub trait SliderVirtuals
+{
+}
+impl<T> ISlider for T where T: SliderVirtuals + SliderInstances +
+                               UIElementVirtuals + UIElementInstances +
+                               ConstructVirtuals + ConstructInstances +
+                               ConcreteDivinator
+{
+}
+impl SliderVirtuals for Slider
+{
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Inherited virtual function overrides
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+//
+// IUIElement overrides.
+//
+impl UIElementVirtuals for Slider
+{
+}
+
+//
+// IConstruct overrides.
+//
+impl ConstructVirtuals for Slider
+{
+    ///
+    /// on_click: Handles click events for this (Slider), according to its concrete type.
+    ///
+    /// THIS METHOD IS PLACEHOLDER ONLY AND WILL BE REMOVED AT A LATER DATE.
+    ///
+    fn on_click(&mut self)
+    {
+        // Carry out base type actions.
+        self.ui_element_mut().on_click();
+
+        // Perform additional work.
+        println!("on_click handled by Slider implementation! \"Inheritance\" FTW!");
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Infrastructure
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// *** Macro Invocations ***
+
+// Implement upcasting and downcasting to ISlider and between interfaces Slider implements.
+provision_transmutation!
+{
+    for Slider: ISlider + [IUIElement IConstruct] extends UIElement
+    {
+        add Fn[as_islider, as_islider_mut] to
+        (
+            UpcastsToISlider,
+            DowncastsToISlider,
+            ISliderDowncaster,
+            DowncastsSliderTypesToISlider: DowncastsLibraryTypesToISlider
+        )
+    }
+}
+
+// Enable downcasts to IUIElement, defined within an upstream library, when Slider is the
+// underlying concrete type.
+extend_downcasting!
+{
+    for IUIElement
+    {
+        add Fn[as_iui_element, as_iui_element_mut] to DowncastsSliderTypesToIUIElement:
+          DowncastsLibraryTypesToIUIElement include [Slider]
+    }
+}
+
+// Provide access to this Slider instance and the encompassed UIElement and Construct instances.
+define_instances! { SliderInstances for Slider : Fn[slider, slider_mut] -> Slider }
+define_instances! { UIElementInstances for Slider : Fn[ui_element, ui_element_mut] ->
+                      UIElement (ui_element) }
+define_instances! { ConstructInstances for Slider : Fn[construct, construct_mut] ->
+                      Construct (ui_element {.construct} {.construct_mut}) }
+
+// Register ISlider, Slider, and this library's downcast handlers (for both ISlider and the
+// upstream IUIElement) with the library that lists this module.
+register_types!
+{
+    types [dyn ISlider = ISLIDER_TYPE_UUID, Slider = SLIDER_TYPE_UUID]
+    handlers
+    [
+        ISliderDowncaster: DowncastsSliderTypesToISlider,
+        IUIElementDowncaster: DowncastsSliderTypesToIUIElement
+    ]
+}
+
+// *** Internal (i.e. public in crate) constants ***
+
+//
+// ISLIDER_TYPE_UUID: The unique identifier for the ISlider type.
+// SLIDER_TYPE_UUID: The unique identifier for the Slider type.
+//
+pub(crate) const ISLIDER_TYPE_UUID: u128 = 89258309472299328112081619584881769899;
+pub(crate) const SLIDER_TYPE_UUID: u128 = 157789284427657063434202772229386724075;
+
+// *** Minutiae ***
+
+// Traits and types
+use rdh::_hierarchy::construct::{ Construct, ConstructInstances, ConstructVirtuals, IConstruct };
+use rdh::_infrastructure::thaumaturgy::ConcreteDivinator;
+use rdh_extension_example::_hierarchy::ui_element::{ DowncastsLibraryTypesToIUIElement, IUIElement,
+                                                     IUIElementDowncaster, UIElement,
+                                                     UIElementInstances, UIElementVirtuals };
+
+// Macros and dependencies
+use rdh::define_instances;
+use rdh::extend_downcasting;
+use rdh::impl_concrete_divinator;
+use rdh::provision_transmutation;
+use rdh::provision_upcasting;
+use rdh::register_types;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{ Arc, OnceLock };
+use rdh::_infrastructure::thaumaturgy::{ CastError, CastFailure, CastTarget, DerivedCasts,
+                                         Divinator, Downcastable, Encompasses, FreezableLock,
+                                         LibraryIdentifier, Lineage, Necromances, ParentAccessors,
+                                         Registered, RegistryError, RegistryLock, RegistryScope,
+                                         Transmuter, TypeIdentifier, TypeKind, TypeRegistry };
+use std::any::TypeId;
@@ -0,0 +1,223 @@
+// ************************************************************************************************
+// Copyright 2023 Gene DeClark and Contributors within this file's version control history
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+// ************************************************************************************************
+
+//!
+//! Within comments throughout the codebase, type names in parentheses indicate an "is a"
+//!  relationship, i.e. that type and any type that encompasses that type.
+//! For example:
+//! * (IToggleSwitch) means an IToggleSwitch trait object or any trait object encompassing
+//!   IToggleSwitch.
+//! * (ToggleSwitch) means the ToggleSwitch struct or any concrete type that implements
+//!   IToggleSwitch.
+//! Whereas:
+//! * IToggleSwitch (no parentheses) means the literal IToggleSwitch trait or its trait objects.
+//! * ToggleSwitch (no parentheses) means the literal ToggleSwitch struct or its instances.
+//!
+
+//!
+//! toggle_switch.rs
+//!
+//! IToggleSwitch (ToggleSwitch)
+//!   The public interface and struct for toggle switches, an example of a type defined within a
+//!   third-party library that encompasses a type (Checkbox) defined within an upstream library.
+//!
+//! Notes:
+//! * ToggleSwitch is made downcastable to ICheckbox and IUIElement by registering it as a derived
+//!   type with ICheckboxDowncaster and IUIElementDowncaster, which register_types! below does
+//!   automatically for each upstream interface listed within provision_transmutation!.
+//!
+//! THIS IS A WORK IN PROGRESS. All current functionality is placeholder only and *will* change.
+//!
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// ToggleSwitch struct
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// ToggleSwitch: The implementation of (ToggleSwitch) functionality.
+///
+pub struct ToggleSwitch
+{
+    //
+    // checkbox: Stores this (ToggleSwitch's) Checkbox instance.
+    //
+    checkbox: Checkbox
+}
+impl ToggleSwitch
+{
+    ///
+    /// new: Creates a new instance of the ToggleSwitch struct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rdh_third_party_example::_hierarchy::toggle_switch::ToggleSwitch;
+    ///
+    /// let toggle_switch = ToggleSwitch::new();
+    /// ```
+    ///
+    pub fn new() -> ToggleSwitch
+    {
+        ToggleSwitch
+        {
+            checkbox: Checkbox::new()
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// IToggleSwitch public interface trait and component / supplemental traits
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// IToggleSwitch: The interface implemented by all (ToggleSwitches).
+//                 * Non-virtual functions are directly declared and implemented below.
+//                 * Virtual functions are declared within the ToggleSwitchVirtuals trait.
+//                   Their base implementation is below-- encompassing types override as needed.
+//                 * Casting functionality provided by additional component and supplemental
+//                   traits.
+///
+pub trait IToggleSwitch : ToggleSwitchVirtuals + UpcastsToIToggleSwitch +
+                          ICheckbox + IUIElement + IConstruct
+{
+}
+pub trait ToggleSwitchVirtuals
+{
+}
+impl<T> IToggleSwitch for T where T: ToggleSwitchVirtuals + ToggleSwitchInstances +
+                                     CheckboxVirtuals + CheckboxInstances +
+                                     UIElementVirtuals + UIElementInstances +
+                                     ConstructVirtuals + ConstructInstances +
+                                     ConcreteDivinator
+{
+}
+impl ToggleSwitchVirtuals for ToggleSwitch
+{
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Inherited virtual function overrides
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+//
+// ICheckbox overrides.
+//
+impl CheckboxVirtuals for ToggleSwitch
+{
+}
+
+//
+// IUIElement overrides.
+//
+impl UIElementVirtuals for ToggleSwitch
+{
+}
+
+//
+// IConstruct overrides.
+//
+impl ConstructVirtuals for ToggleSwitch
+{
+    ///
+    /// on_click: Handles click events for this (ToggleSwitch), according to its concrete type.
+    ///
+    /// THIS METHOD IS PLACEHOLDER ONLY AND WILL BE REMOVED AT A LATER DATE.
+    ///
+    fn on_click(&mut self)
+    {
+        // Carry out base type actions.
+        self.checkbox_mut().on_click();
+
+        // Perform additional work.
+        println!("on_click handled by ToggleSwitch implementation! \"Inheritance\" FTW!");
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Infrastructure
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// *** Macro Invocations ***
+
+// Implement upcasting and downcasting to IToggleSwitch and between interfaces ToggleSwitch
+// implements.
+provision_transmutation!
+{
+    for ToggleSwitch: IToggleSwitch + [ICheckbox IUIElement IConstruct] extends Checkbox
+    {
+        add Fn[as_itoggle_switch, as_itoggle_switch_mut] to
+        (
+            UpcastsToIToggleSwitch,
+            DowncastsToIToggleSwitch,
+            IToggleSwitchDowncaster,
+            DowncastsToggleTypesToIToggleSwitch: DowncastsLibraryTypesToIToggleSwitch
+        )
+    }
+}
+
+// Provide access to this ToggleSwitch instance and the encompassed Checkbox, UIElement, and
+// Construct instances.
+define_instances! { ToggleSwitchInstances for ToggleSwitch :
+                      Fn[toggle_switch, toggle_switch_mut] -> ToggleSwitch }
+define_instances! { CheckboxInstances for ToggleSwitch : Fn[checkbox, checkbox_mut] ->
+                      Checkbox (checkbox) }
+define_instances! { UIElementInstances for ToggleSwitch : Fn[ui_element, ui_element_mut] ->
+                      UIElement (checkbox {.ui_element} {.ui_element_mut}) }
+define_instances! { ConstructInstances for ToggleSwitch : Fn[construct, construct_mut] ->
+                      Construct (checkbox {.construct} {.construct_mut}) }
+
+// Register IToggleSwitch, ToggleSwitch, and this library's IToggleSwitch downcast handler with the
+// library that lists this module, and make ToggleSwitch downcastable to the public interfaces
+// introduced by its upstream ancestors (other than IConstruct).
+register_types!
+{
+    types [dyn IToggleSwitch = ITOGGLE_SWITCH_TYPE_UUID, ToggleSwitch = TOGGLE_SWITCH_TYPE_UUID]
+    handlers [IToggleSwitchDowncaster: DowncastsToggleTypesToIToggleSwitch]
+}
+
+// *** Internal (i.e. public in crate) constants ***
+
+//
+// ITOGGLE_SWITCH_TYPE_UUID: The unique identifier for the IToggleSwitch type.
+// TOGGLE_SWITCH_TYPE_UUID: The unique identifier for the ToggleSwitch type.
+//
+pub(crate) const ITOGGLE_SWITCH_TYPE_UUID: u128 = 310263114118084797803168773009770851538;
+pub(crate) const TOGGLE_SWITCH_TYPE_UUID: u128 = 98757528513927031986526868304254672636;
+
+// *** Minutiae ***
+
+// Traits and types
+use rdh::_hierarchy::construct::{ Construct, ConstructInstances, ConstructVirtuals, IConstruct };
+use rdh::_infrastructure::thaumaturgy::ConcreteDivinator;
+use rdh_extension_example::_hierarchy::checkbox::{ Checkbox, CheckboxInstances, CheckboxVirtuals,
+                                                   ICheckbox };
+use rdh_extension_example::_hierarchy::ui_element::{ IUIElement, UIElement, UIElementInstances,
+                                                     UIElementVirtuals };
+
+// Macros and dependencies
+use rdh::define_instances;
+use rdh::extend_downcasting;
+use rdh::impl_concrete_divinator;
+use rdh::provision_transmutation;
+use rdh::provision_upcasting;
+use rdh::register_types;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{ Arc, OnceLock };
+use rdh::_infrastructure::thaumaturgy::{ CastError, CastFailure, CastTarget, DerivedCasts,
+                                         Divinator, Downcastable, Encompasses, FreezableLock,
+                                         LibraryIdentifier, Lineage, Necromances, ParentAccessors,
+                                         Registered, RegistryError, RegistryLock, RegistryScope,
+                                         Transmuter, TypeIdentifier, TypeKind, TypeRegistry };
+use std::any::TypeId;
@@ -0,0 +1,124 @@
+// ************************************************************************************************
+// Copyright 2023 Gene DeClark and Contributors within this file's version control history
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+// ************************************************************************************************
+
+//!
+//! lib.rs (rdh_third_party_example)
+//!
+//! Contains module declarations for and initialization of this example third-party extension to
+//! the Rust Development Hierarchy, whose types encompass and implement types and interfaces
+//! defined within rdh_extension_example.
+//!
+//! Notes:
+//! * For brevity, this crate hosts two sibling RDH libraries, each with its own library UUID:
+//!   * Toggles (ToggleSwitch), wired for downcasting as a derived type (see DerivedCasts).
+//!   * Sliders (Slider), wired for downcasting via extend_downcasting! and register_handler().
+//! * Both depend upon rdh_extension_example, and LIBRARY depends upon both, forming a
+//!   diamond-shaped library dependency graph.
+//!
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// External crates
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+extern crate rdh;
+extern crate rdh_extension_example;
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Public modules
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod _hierarchy;
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Public initialization functions
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+provision_library!
+{
+    ///
+    /// LIBRARY: This example third-party extension's place within the library dependency graph,
+    ///          encompassing both of its sibling libraries.
+    ///
+    pub static LIBRARY: "rdh_third_party_example" (RDH_THIRD_PARTY_UUID)
+      version env!("CARGO_PKG_VERSION"), requires rdh "0.5.0",
+      extends [TOGGLES, SLIDERS];
+}
+
+provision_library!
+{
+    ///
+    /// TOGGLES: The Toggles library's place within the library dependency graph.
+    ///
+    pub static TOGGLES: "Toggles" (TOGGLES_UUID)
+      version env!("CARGO_PKG_VERSION"), requires rdh "0.5.0",
+      extends [rdh_extension_example::LIBRARY]
+    {
+        _hierarchy::toggle_switch
+    }
+}
+
+provision_library!
+{
+    ///
+    /// SLIDERS: The Sliders library's place within the library dependency graph.
+    ///
+    pub static SLIDERS: "Sliders" (SLIDERS_UUID)
+      version env!("CARGO_PKG_VERSION"), requires rdh "0.5.0",
+      extends [rdh_extension_example::LIBRARY]
+    {
+        _hierarchy::slider
+    }
+}
+
+///
+/// init: Initializes this example third-party RDH extension library and its ancestors.
+///
+/// Project main() function must invoke the init() function for the top-level RDH library.
+///
+/// Notes:
+/// * Applications consuming only one of the sibling libraries may initialize it alone (e.g.
+///   SLIDERS.init()), or several via init_all().
+///
+pub fn init()
+{
+    //
+    // Implementation notes:
+    //
+    // * The init() function for all RDH libraries must initialize their own LIBRARY, which
+    //   initializes its parent libraries first.
+    //   * rdh_extension_example, shared by both sibling libraries, is initialized only once.
+    //
+
+    LIBRARY.init();
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Private initialization infrastructure
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// *** Private constants ***
+
+//
+// RDH_THIRD_PARTY_UUID: The unique identifier for this example third-party RDH extension library.
+// TOGGLES_UUID: The unique identifier for the Toggles library.
+// SLIDERS_UUID: The unique identifier for the Sliders library.
+//
+const RDH_THIRD_PARTY_UUID: u128 = 309430684369836919597440102834869764423;
+const TOGGLES_UUID: u128 = 278732395841884849562515162403790226364;
+const SLIDERS_UUID: u128 = 218883071393464810693775543932854433156;
+
+// *** Minutiae ***
+
+use rdh::_infrastructure::initialization::Library;
+use rdh::provision_library;
@@ -16,16 +16,42 @@ extern crate rdh;
 extern crate rdh_extension_example;
 
 use rdh::_hierarchy::construct::{ Construct, IConstruct };
-use rdh::_infrastructure::thaumaturgy::{ ConcreteDivinator, Divinator, TypeRegistry };
-use rdh_extension_example::_hierarchy::ui_element::{ IUIElement, UIElement };
+use rdh::_infrastructure::initialization::{ init_all, InitError, Library, RDH_VERSION };
+use rdh::_infrastructure::manifest::TypeManifest;
+use rdh::_infrastructure::reader_writer_lock::{ LockRecursionPolicy, ReaderWriterLock };
+use rdh::_infrastructure::thaumaturgy::{ CastFailure, CastTarget, ConcreteDivinator, Divinator,
+                                         FreezableLock, LibraryIdentifier, LibraryRecord,
+                                         Necromances, Registered, RegistryError, RegistryScope,
+                                         Transmuter, Transmutes, TypeIdentifier, TypeRegistry };
+use rdh::_infrastructure::uuid::{ name_uuid, parse_uuid };
+use rdh::{ name_uuid, uuid };
+use rdh_extension_example::_extras::type_dispatch_examples::{
+    dispatch_iconstruct_by_implemented_interface };
+use rdh_extension_example::_hierarchy::checkbox::{ Checkbox, DowncastsToICheckbox, ICheckbox,
+                                                   ICheckboxDowncaster };
+use rdh_extension_example::_hierarchy::property::{ IProperty, Property, PropertyFamily,
+                                                   UpcastsToIProperty };
+use rdh_extension_example::_hierarchy::ui_element::{ DowncastsRdhExtensionTypesToIUIElement,
+                                                     DowncastsToIUIElement, IUIElement,
+                                                     IUIElementDowncaster, UIElement };
+use rdh_third_party_example::_hierarchy::slider::{ DowncastsSliderTypesToIUIElement, ISlider,
+                                                   Slider };
+use rdh_third_party_example::_hierarchy::toggle_switch::{ DowncastsToIToggleSwitch,
+                                                          IToggleSwitch, ToggleSwitch };
+use std::any::TypeId;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use std::sync::{ mpsc, Arc };
+use std::thread;
+use std::time::Duration;
 
 fn test_concrete_identifiers()
 {
-    println!("");
+    println!();
     println!("**********************************************************************************");
     println!("ConcreteDivinator::concrete_type_identifier() and concrete_library_identifier():");
     println!("**********************************************************************************");
-    println!("");
+    println!();
 
     let construct = Construct::new();
 
@@ -45,7 +71,7 @@ fn test_concrete_identifiers()
     // Should be 88009063541924297814935272469493149666:
     println!("{:?}", iconstruct.concrete_library_identifier());
 
-    println!("");
+    println!();
 
     let ui_element = UIElement::new();
 
@@ -71,16 +97,16 @@ fn test_concrete_identifiers()
     // Should be 41377672462483076378035019486899738808:
     println!("{:?}", iui_element.concrete_library_identifier());
 
-    println!("");
+    println!();
 }
 
 fn test_concrete_implements()
 {
-    println!("");
+    println!();
     println!("**********************************************************************************");
     println!("ConcreteDivinator::implements():");
     println!("**********************************************************************************");
-    println!("");
+    println!();
 
     let construct = Construct::new();
 
@@ -110,7 +136,7 @@ fn test_concrete_implements()
     // Should be false:
     println!("{}", iconstruct.implements(TypeRegistry::type_identifier_of::<UIElement>()));
 
-    println!("");
+    println!();
     
     let ui_element = UIElement::new();
 
@@ -154,16 +180,16 @@ fn test_concrete_implements()
     // Should be false:
     println!("{}", iui_element.implements(TypeRegistry::type_identifier_of::<UIElement>()));
 
-    println!("");
+    println!();
 }
 
 fn test_concrete_is()
 {
-    println!("");
+    println!();
     println!("**********************************************************************************");
     println!("ConcreteDivinator::is():");
     println!("**********************************************************************************");
-    println!("");
+    println!();
 
     let construct = Construct::new();
 
@@ -193,7 +219,7 @@ fn test_concrete_is()
     // Should be false:
     println!("{}", iconstruct.is(TypeRegistry::type_identifier_of::<dyn IUIElement>()));
 
-    println!("");
+    println!();
     
     let ui_element = UIElement::new();
 
@@ -237,16 +263,70 @@ fn test_concrete_is()
     // Should be false:
     println!("{}", iui_element.is(TypeRegistry::type_identifier_of::<dyn IUIElement>()));
 
-    println!("");
+    println!();
+}
+
+fn test_concrete_is_a()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("ConcreteDivinator::is_a():");
+    println!("**********************************************************************************");
+    println!();
+
+    let checkbox = Checkbox::new();
+
+    // Should be true:
+    println!("{}", checkbox.is_a(TypeRegistry::type_identifier_of::<Checkbox>()));
+
+    // Should be true:
+    println!("{}", checkbox.is_a(TypeRegistry::type_identifier_of::<UIElement>()));
+
+    // Should be true:
+    println!("{}", checkbox.is_a(TypeRegistry::type_identifier_of::<Construct>()));
+
+    // Should be false:
+    println!("{}", checkbox.is_a(TypeRegistry::type_identifier_of::<dyn IUIElement>()));
+
+    let iconstruct = &checkbox as &dyn IConstruct;
+
+    // Should be true:
+    println!("{}", iconstruct.is_a(TypeRegistry::type_identifier_of::<UIElement>()));
+
+    // Should be false:
+    println!("{}", iconstruct.is(TypeRegistry::type_identifier_of::<UIElement>()));
+
+    println!();
+
+    let ui_element = UIElement::new();
+
+    // Should be false:
+    println!("{}", ui_element.is_a(TypeRegistry::type_identifier_of::<Checkbox>()));
+
+    // Should be true:
+    println!("{}", ui_element.is_a(TypeRegistry::type_identifier_of::<Construct>()));
+
+    let property = Property::new(true);
+
+    // Should be true:
+    println!("{}", property.is_a(TypeRegistry::family_identifier_of::<PropertyFamily>()));
+
+    // Should be true:
+    println!("{}", property.is_a(TypeRegistry::type_identifier_of::<Construct>()));
+
+    // Should be false:
+    println!("{}", property.is_a(TypeRegistry::type_identifier_of::<UIElement>()));
+
+    println!();
 }
 
 fn test_divinator()
 {
-    println!("");
+    println!();
     println!("**********************************************************************************");
     println!("Divinator::type_identifier() and library_identifier():");
     println!("**********************************************************************************");
-    println!("");
+    println!();
 
     let construct = Construct::new();
 
@@ -264,7 +344,7 @@ fn test_divinator()
     // Should be 88009063541924297814935272469493149666:
     println!("{:?}", iconstruct.library_identifier());
 
-    println!("");
+    println!();
 
     let ui_element = UIElement::new();
 
@@ -290,17 +370,1172 @@ fn test_divinator()
     // Should be 41377672462483076378035019486899738808:
     println!("{:?}", iui_element.library_identifier());
 
-    println!("");
+    println!();
+}
+
+fn test_type_names()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("TypeRegistry::type_name_of():");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should be rdh::_hierarchy::construct::Construct:
+    println!("{}", TypeRegistry::type_name_of::<Construct>());
+
+    // Should be dyn rdh::_hierarchy::construct::IConstruct:
+    println!("{}", TypeRegistry::type_name_of::<dyn IConstruct>());
+
+    // Should be rdh_extension_example::_hierarchy::ui_element::UIElement:
+    println!("{}", TypeRegistry::type_name_of::<UIElement>());
+
+    // Should be dyn rdh_extension_example::_hierarchy::ui_element::IUIElement:
+    println!("{}", TypeRegistry::type_name_of::<dyn IUIElement>());
+
+    println!();
+}
+
+fn test_generic_types()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Generic types and TypeRegistry::family_identifier_of():");
+    println!("**********************************************************************************");
+    println!();
+
+    let bool_property = Property::new(true);
+    let string_property = Property::new("text".to_string());
+
+    // Should be 86278656256766546894230692424726747909 / 41377672462483076378035019486899738808:
+    println!("{:?}", bool_property.concrete_type_identifier());
+
+    // Should be 293384473180908580373889959043585276323 / 41377672462483076378035019486899738808:
+    println!("{:?}", string_property.concrete_type_identifier());
+
+    // Should be 86157536459032462056507995944814102077 / 41377672462483076378035019486899738808:
+    println!("{:?}", TypeRegistry::family_identifier_of::<PropertyFamily>());
+
+    let iproperty = &bool_property as &dyn IProperty;
+
+    // Should be true:
+    println!("{}", iproperty.is(TypeRegistry::type_identifier_of::<Property<bool>>()));
+
+    // Should be false:
+    println!("{}", iproperty.is(TypeRegistry::type_identifier_of::<Property<String>>()));
+
+    // Should be true:
+    println!("{}", iproperty.is(TypeRegistry::family_identifier_of::<PropertyFamily>()));
+
+    // Should be true:
+    println!("{}", iproperty.implements(TypeRegistry::type_identifier_of::<dyn IProperty>()));
+
+    // Should be false:
+    println!("{}", iproperty.implements(TypeRegistry::family_identifier_of::<PropertyFamily>()));
+
+    let iconstruct = &string_property as &dyn IConstruct;
+
+    // Should be true:
+    println!("{}", iconstruct.is(TypeRegistry::type_identifier_of::<Property<String>>()));
+
+    // Should be true:
+    println!("{}", iconstruct.is(TypeRegistry::family_identifier_of::<PropertyFamily>()));
+
+    // Should be false:
+    println!("{}", UIElement::new().is(TypeRegistry::family_identifier_of::<PropertyFamily>()));
+
+    // Should be bool / alloc::string::String:
+    println!("{} / {}", iproperty.value_type_name(),
+             string_property.as_iproperty().value_type_name());
+
+    println!();
+}
+
+fn test_registry_errors()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("TypeRegistry::try_*() and (IConstruct)Downcaster::try_register_handler():");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should be Err(UnregisteredType { type_name: "...Property<u8>" }):
+    println!("{:?}", TypeRegistry::try_type_identifier_of::<Property<u8>>());
+
+    // Should be Err(DuplicateType { type_name: "...UIElement" }):
+    println!("{:?}", TypeRegistry::try_register_type::<UIElement>(
+                       1, 41377672462483076378035019486899738808));
+
+    // Should be Err(DuplicateUuid { uuid: 216176100787323494951291243294143776575, ... }):
+    println!("{:?}", TypeRegistry::try_register_type::<Property<u8>>(
+                       216176100787323494951291243294143776575,
+                       41377672462483076378035019486899738808));
+
+    // Should be Specified UUID (a2a200a8-de27-4bf9-934f-44626ae3873f) for ...Property<u8>
+    //  already registered to a different type!
+    if let Err(error) = TypeRegistry::try_register_type::<Property<u8>>(
+                          216176100787323494951291243294143776575,
+                          41377672462483076378035019486899738808)
+    {
+        println!("{}", error);
+    }
+
+    // Should be true:
+    println!("{}", matches!(IUIElementDowncaster::try_register_handler(
+                              TypeRegistry::library_identifier_of::<UIElement>(),
+                              Box::new(DowncastsRdhExtensionTypesToIUIElement{})),
+                            Err(RegistryError::DuplicateHandler { .. })));
+
+    // Should be Ok(TypeIdentifier { type_uuid: 216176100787323494951291243294143776575, .. }):
+    println!("{:?}", TypeRegistry::try_type_identifier_of::<UIElement>());
+
+    println!();
+}
+
+fn test_registry_enumeration()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("TypeRegistry::types(), types_in_library(), interfaces(), families(), and \
+              record_of_uuid():");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should be 13 (dyn IConstruct, Construct, the 4 UIElement / Checkbox types, dyn IProperty,
+    // the 2 Property<T> instantiations, and the 4 third-party ToggleSwitch / Slider types):
+    println!("{}", TypeRegistry::types().len());
+
+    // Should list dyn IConstruct and Construct only:
+    for type_record in TypeRegistry::types_in_library(
+                         TypeRegistry::library_identifier_of::<Construct>())
+    {
+        println!("{} ({:?})", type_record.type_name(), type_record.kind());
+    }
+
+    // Should be 6 (dyn IConstruct, dyn IUIElement, dyn ICheckbox, dyn IProperty, dyn IToggleSwitch,
+    // and dyn ISlider):
+    println!("{}", TypeRegistry::interfaces().len());
+
+    // Should be 1 (PropertyFamily):
+    println!("{}", TypeRegistry::families().len());
+
+    // Should be rdh_extension_example::_hierarchy::ui_element::UIElement:
+    println!("{}", TypeRegistry::record_of_uuid(216176100787323494951291243294143776575)
+                     .map_or("None", |type_record| type_record.type_name()));
+
+    // Should be true:
+    println!("{}", TypeRegistry::record_of_uuid(216176100787323494951291243294143776575)
+                     .is_some_and(|type_record|
+                                    type_record.type_identifier() ==
+                                      TypeRegistry::type_identifier_of::<UIElement>()));
+
+    // Should be None:
+    println!("{:?}", TypeRegistry::record_of_uuid(1));
+
+    println!();
+}
+
+fn test_registry_lineage()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("TypeRegistry::parent_of(), ancestors_of(), descendants_of(), interfaces_of(), and \
+              types_implementing():");
+    println!("**********************************************************************************");
+    println!();
+
+    let construct_type = TypeRegistry::type_identifier_of::<Construct>();
+    let ui_element_type = TypeRegistry::type_identifier_of::<UIElement>();
+    let checkbox_type = TypeRegistry::type_identifier_of::<Checkbox>();
+    let toggle_switch_type = TypeRegistry::type_identifier_of::<ToggleSwitch>();
+    let slider_type = TypeRegistry::type_identifier_of::<Slider>();
+
+    // Should be true:
+    println!("{}", TypeRegistry::parent_of(checkbox_type) == Some(ui_element_type));
+
+    // Should be None:
+    println!("{:?}", TypeRegistry::parent_of(construct_type));
+
+    // Should be true:
+    println!("{}", TypeRegistry::ancestors_of(checkbox_type) ==
+                     vec![ui_element_type, construct_type]);
+
+    // Should be true (ordered by UUID):
+    println!("{}", TypeRegistry::descendants_of(ui_element_type) ==
+                     vec![checkbox_type, toggle_switch_type, slider_type]);
+
+    // Should be 6 (UIElement, Checkbox, ToggleSwitch, Slider, Property<bool>, and
+    // Property<String>):
+    println!("{}", TypeRegistry::descendants_of(construct_type).len());
+
+    // Should be true:
+    println!("{}", TypeRegistry::interfaces_of(checkbox_type) ==
+                     vec![TypeRegistry::type_identifier_of::<dyn ICheckbox>(),
+                          TypeRegistry::type_identifier_of::<dyn IUIElement>(),
+                          TypeRegistry::type_identifier_of::<dyn IConstruct>()]);
+
+    // Should be true (ordered by UUID):
+    println!("{}", TypeRegistry::types_implementing(
+                     TypeRegistry::type_identifier_of::<dyn IUIElement>()) ==
+                       vec![checkbox_type, toggle_switch_type, slider_type, ui_element_type]);
+
+    // Should be 2 (Property<bool> and Property<String>):
+    println!("{}", TypeRegistry::types_implementing(
+                     TypeRegistry::type_identifier_of::<dyn IProperty>()).len());
+
+    // Should be None, then true (Checkbox's type UUID, but the wrong library):
+    let misattributed_type = TypeIdentifier::new(checkbox_type.type_uuid(),
+                                                 construct_type.library_identifier());
+    println!("{:?}", TypeRegistry::parent_of(misattributed_type));
+    println!("{}", TypeRegistry::interfaces_of(misattributed_type).is_empty());
+
+    println!();
+}
+
+fn test_necromancer_ancestors()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Necromances::as_ancestor() and as_ancestor_mut():");
+    println!("**********************************************************************************");
+    println!();
+
+    let mut checkbox = Checkbox::new();
+    let iconstruct = &checkbox as &dyn IConstruct;
+
+    // Should be false:
+    println!("{}", iconstruct.as_concrete::<UIElement>().is_some());
+
+    // Should be true:
+    println!("{}", iconstruct.as_ancestor::<UIElement>().is_some());
+
+    // Should be true:
+    println!("{}", iconstruct.as_ancestor::<Checkbox>().is_some());
+
+    // Should be true:
+    println!("{}", iconstruct.as_ancestor::<Construct>().is_some());
+
+    let iconstruct_mut = &mut checkbox as &mut dyn IConstruct;
+    if let Some(construct) = iconstruct_mut.as_ancestor_mut::<Construct>()
+    {
+        construct.set_name("Recovered");
+    }
+
+    // Should be Recovered:
+    println!("{}", checkbox.get_name());
+
+    let ui_element = UIElement::new();
+    let iconstruct2 = &ui_element as &dyn IConstruct;
+
+    // Should be false:
+    println!("{}", iconstruct2.as_ancestor::<Checkbox>().is_some());
+
+    // Should be false (neither type is registered within a new RegistryScope):
+    {
+        let _scope = RegistryScope::enter();
+        println!("{}", iconstruct2.as_ancestor::<Construct>().is_some());
+    }
+
+    let mut property = Property::new(true);
+    let iconstruct3 = &mut property as &mut dyn IConstruct;
+    if let Some(construct) = iconstruct3.as_ancestor_mut::<Construct>()
+    {
+        construct.set_name("Flag");
+    }
+
+    // Should be Flag:
+    println!("{}", property.get_name());
+
+    println!();
+}
+
+fn test_owned_casts()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Necromances::into_concrete(_rc/_arc)() and owned interface downcasts:");
+    println!("**********************************************************************************");
+    println!();
+
+    let boxed: Box<dyn IConstruct> = Box::new(Checkbox::new());
+
+    // Should be false:
+    let boxed = boxed.into_concrete::<UIElement>().err().unwrap();
+    println!("{}", boxed.is(TypeRegistry::type_identifier_of::<UIElement>()));
+
+    // Should be true:
+    let checkbox: Box<Checkbox> = boxed.into_concrete::<Checkbox>().ok().unwrap();
+    println!("{}", checkbox.is(TypeRegistry::type_identifier_of::<Checkbox>()));
+
+    let shared: Rc<dyn IConstruct> = Rc::new(Checkbox::new());
+    let shared_clone = Rc::clone(&shared);
+
+    // Should be 2:
+    let icheckbox: Rc<dyn ICheckbox> = ICheckboxDowncaster::downcast_rc(shared).ok().unwrap();
+    println!("{}", Rc::strong_count(&icheckbox));
+
+    // Should be true:
+    println!("{}", shared_clone.into_concrete_rc::<Checkbox>().is_ok());
+
+    let ui_element: Arc<dyn IConstruct> = Arc::new(UIElement::new());
+
+    // Should be true:
+    let ui_element = ICheckboxDowncaster::downcast_arc(ui_element).err().unwrap();
+    println!("{}", ui_element.is(TypeRegistry::type_identifier_of::<UIElement>()));
+
+    // Should be true:
+    let iui_element = Transmuter::transmute_arc(ui_element, IUIElementDowncaster::as_iui_element);
+    println!("{}", iui_element.is_ok());
+
+    let mut checkbox_box: Box<dyn IConstruct> = Box::new(Checkbox::new());
+    checkbox_box.set_name("Owned");
+
+    // Should be Owned:
+    let icheckbox_box = ICheckboxDowncaster::downcast_box(checkbox_box).ok().unwrap();
+    println!("{}", icheckbox_box.get_name());
+
+    // Should be false (ancestors are never transferred ownership):
+    let checkbox_box = icheckbox_box as Box<dyn IConstruct>;
+    let result = Transmuter::transmute_box(checkbox_box,
+                                           |iconstruct| iconstruct.as_ancestor_mut::<Construct>());
+    println!("{}", result.is_ok());
+
+    // Should be true (identity checks never consult the TypeRegistry, so an empty RegistryScope
+    // doesn't matter):
+    {
+        let _scope = RegistryScope::enter();
+        let boxed: Box<dyn IConstruct> = Box::new(Checkbox::new());
+        let result =
+          Transmuter::transmute_box(boxed, |iconstruct| iconstruct.as_concrete_mut::<Checkbox>());
+        println!("{}", result.is_ok());
+    }
+
+    println!();
+}
+
+fn test_cast_errors()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Necromances::try_as_concrete() and (IConstruct)Downcaster::try_downcast():");
+    println!("**********************************************************************************");
+    println!();
+
+    let ui_element = UIElement::new();
+    let iconstruct = &ui_element as &dyn IConstruct;
+
+    // Should be true:
+    println!("{}", iconstruct.try_as_concrete::<UIElement>().is_ok());
+
+    // Should be Unable to cast ...UIElement (rdh_extension_example 0.5.0) to ...Checkbox
+    //  (rdh_extension_example 0.5.0): Underlying concrete type is not (and does not implement) the
+    //  target type!
+    if let Err(error) = iconstruct.try_as_concrete::<Checkbox>()
+    {
+        println!("{}", error);
+    }
+
+    // Should be true:
+    let error = ICheckboxDowncaster::try_downcast(iconstruct).err().unwrap();
+    println!("{}", error.reason() == CastFailure::NotTargetType);
+
+    // Should be true:
+    println!("{}", error.source_type() == Some(TypeRegistry::type_identifier_of::<UIElement>()));
+
+    // Should be true:
+    println!("{}",
+             error.target_type() == Some(TypeRegistry::type_identifier_of::<dyn ICheckbox>()));
+
+    let mut checkbox = Checkbox::new();
+    let iconstruct_mut = &mut checkbox as &mut dyn IConstruct;
+
+    // Should be true:
+    println!("{}", IUIElementDowncaster::try_downcast_mut(iconstruct_mut).is_ok());
+
+    {
+        // Only rdh's own types are registered within this scope.
+        let _scope = RegistryScope::enter();
+        rdh::init();
+
+        // Should be Unable to cast an unregistered type to an unregistered type: Indicated type
+        //  (...Checkbox) has not been registered!
+        if let Err(error) = IUIElementDowncaster::try_downcast(&checkbox)
+        {
+            println!("{}", error);
+        }
+
+        // Should be true, then true:
+        let iconstruct_mut = &mut checkbox as &mut dyn IConstruct;
+        let error = IUIElementDowncaster::try_downcast_mut(iconstruct_mut).err().unwrap();
+        println!("{}", matches!(error.reason(), CastFailure::UnregisteredType { .. }));
+        println!("{}", error.source_type().is_none());
+
+        // Should be true (Construct is registered, but Checkbox isn't):
+        let error = (&checkbox as &dyn IConstruct).try_as_concrete::<Construct>().err().unwrap();
+        println!("{}",
+                 error.target_type() == Some(TypeRegistry::type_identifier_of::<Construct>()));
+    }
+
+    println!();
+}
+
+fn test_derived_type_registration()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("(IConstruct)Downcaster::try_register_derived_type():");
+    println!("**********************************************************************************");
+    println!();
+
+    let mut checkbox = Checkbox::new();
+
+    // Should be true (Checkbox is not within UIElement's include list):
+    println!("{}", (&checkbox as &dyn IConstruct).as_iui_element().is_some());
+
+    // Should be true:
+    println!("{}", (&mut checkbox as &mut dyn IConstruct).as_iui_element_mut().is_some());
+
+    // Should be Indicated derived type (...Checkbox) has already been registered with
+    //  IUIElementDowncaster!
+    if let Err(error) = IUIElementDowncaster::try_register_derived_type::<Checkbox>()
+    {
+        println!("{}", error);
+    }
+
+    // Should be true:
+    println!("{}", matches!(IUIElementDowncaster::try_register_derived_type::<Checkbox>(),
+                            Err(RegistryError::DuplicateDerivedType { .. })));
+
+    println!();
+}
+
+fn test_third_party_types()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Downcasting third-party types to upstream interfaces:");
+    println!("**********************************************************************************");
+    println!();
+
+    let toggle_switch = ToggleSwitch::new();
+    let slider = Slider::new();
+    let itoggle_switch = &toggle_switch as &dyn IConstruct;
+    let islider = &slider as &dyn IConstruct;
+
+    // Should be true (registered automatically as a derived type):
+    println!("{}", itoggle_switch.as_icheckbox().is_some());
+
+    // Should be true (registered automatically as a derived type):
+    println!("{}", itoggle_switch.as_iui_element().is_some());
+
+    // Should be true:
+    println!("{}", itoggle_switch.as_itoggle_switch().is_some());
+
+    // Should be true (handled by the Sliders library's handler):
+    println!("{}", islider.as_iui_element().is_some());
+
+    // Should be false:
+    println!("{}", islider.as_icheckbox().is_some());
+
+    // Should be true:
+    println!("{}", islider.implements(TypeRegistry::type_identifier_of::<dyn ISlider>()));
+
+    // Should be false:
+    println!("{}", islider.implements(TypeRegistry::type_identifier_of::<dyn IToggleSwitch>()));
+
+    // Should be on_click messages for Construct, UIElement, Checkbox, and ToggleSwitch:
+    let mut toggle_switch_mut = ToggleSwitch::new();
+    if let Some(icheckbox_mut) = (&mut toggle_switch_mut as &mut dyn IConstruct).as_icheckbox_mut()
+    {
+        icheckbox_mut.on_click();
+    }
+
+    // Should be Ok(()) (a second, distinct handler for the Sliders library):
+    println!("{:?}", IUIElementDowncaster::try_register_handler(
+                       TypeRegistry::library_identifier_of::<Slider>(),
+                       Box::new(DowncastsRdhExtensionTypesToIUIElement{})));
+
+    // Should be true:
+    println!("{}", matches!(IUIElementDowncaster::try_register_handler(
+                              TypeRegistry::library_identifier_of::<Slider>(),
+                              Box::new(DowncastsSliderTypesToIUIElement{})),
+                            Err(RegistryError::DuplicateHandler { .. })));
+
+    // Should be true:
+    println!("{}", islider.as_iui_element().is_some());
+
+    println!();
+}
+
+fn test_dispatch_tables()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("ConcreteDivinator::concrete_type_id() and table-driven downcasting:");
+    println!("**********************************************************************************");
+    println!();
+
+    let checkbox = Checkbox::new();
+    let toggle_switch = ToggleSwitch::new();
+    let slider = Slider::new();
+    let icheckbox = &checkbox as &dyn IConstruct;
+    let itoggle_switch = &toggle_switch as &dyn IConstruct;
+    let islider = &slider as &dyn IConstruct;
+
+    // Should be true:
+    println!("{}", icheckbox.concrete_type_id() == TypeId::of::<Checkbox>());
+
+    // Should be false:
+    println!("{}", icheckbox.concrete_type_id() == TypeId::of::<UIElement>());
+
+    // Should be 30000 (each downcast resolved via a single dispatch table lookup):
+    let mut successes = 0;
+    for _ in 0..10000
+    {
+        for iconstruct in [icheckbox, itoggle_switch, islider]
+        {
+            if iconstruct.as_iui_element().is_some()
+            {
+                successes += 1;
+            }
+        }
+    }
+    println!("{}", successes);
+
+    // Should be 0:
+    let mut failures = 0;
+    for _ in 0..10000
+    {
+        if islider.as_icheckbox().is_some()
+        {
+            failures += 1;
+        }
+    }
+    println!("{}", failures);
+
+    println!();
+}
+
+fn test_cached_identifiers()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("TypeRegistry::cached_type_identifier_of() and family_of():");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should be true (twice, the second time from the cache):
+    for _ in 0..2
+    {
+        println!("{}", TypeRegistry::cached_type_identifier_of::<Checkbox>() ==
+                         TypeRegistry::type_identifier_of::<Checkbox>());
+    }
+
+    // Should be None:
+    println!("{:?}", TypeRegistry::family_of::<Checkbox>());
+
+    // Should be true:
+    println!("{}", TypeRegistry::family_of::<Property<bool>>() ==
+                     Some(TypeRegistry::family_identifier_of::<PropertyFamily>()));
+
+    // Should be true:
+    println!("{}", matches!(TypeRegistry::try_family_of::<dyn IProperty>(), Ok(None)));
+
+    // Should be 10000 (each is() and implements() call only comparing cached identifiers):
+    let checkbox = Checkbox::new();
+    let icheckbox = &checkbox as &dyn IConstruct;
+    let checkbox_type = TypeRegistry::type_identifier_of::<Checkbox>();
+    let iui_element_type = TypeRegistry::type_identifier_of::<dyn IUIElement>();
+    let mut matches = 0;
+    for _ in 0..10000
+    {
+        if icheckbox.is(checkbox_type) && icheckbox.implements(iui_element_type)
+        {
+            matches += 1;
+        }
+    }
+    println!("{}", matches);
+
+    println!();
+}
+
+fn test_automatic_registration()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("register_types! and provision_library!:");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should list dyn ISlider and Slider only (registered via the Sliders library's module list):
+    for type_record in TypeRegistry::types_in_library(
+                         TypeRegistry::library_identifier_of::<Slider>())
+    {
+        println!("{} ({:?})", type_record.type_name(), type_record.kind());
+    }
+
+    // Should be true (the Sliders library's UUID):
+    println!("{}", TypeRegistry::library_identifier_of::<Slider>() ==
+                     LibraryIdentifier::from_uuid(218883071393464810693775543932854433156));
+
+    // Should be true (registered for the Sliders library via handlers [...]):
+    println!("{}", matches!(IUIElementDowncaster::try_register_handler(
+                              LibraryIdentifier::from_uuid(218883071393464810693775543932854433156),
+                              Box::new(DowncastsSliderTypesToIUIElement{})),
+                            Err(RegistryError::DuplicateHandler { .. })));
+
+    // Should be true (registered with IUIElementDowncaster automatically, as a derived type):
+    println!("{}", matches!(IUIElementDowncaster::try_register_derived_type::<ToggleSwitch>(),
+                            Err(RegistryError::DuplicateDerivedType { .. })));
+
+    // Should compile (each type, interface, and handler is marked as Registered):
+    fn assert_registered<T>() where T: Registered + ?Sized {}
+    assert_registered::<dyn IConstruct>();
+    assert_registered::<Checkbox>();
+    assert_registered::<Property<bool>>();
+    assert_registered::<DowncastsSliderTypesToIUIElement>();
+
+    println!();
+}
+
+fn test_reader_writer_lock()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("ReaderWriterLock and FreezableLock timeouts:");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should be Some(Timeout) (another thread holds the write lock):
+    let lock = ReaderWriterLock::new(0);
+    while_locked_elsewhere(|| lock.write().unwrap(),
+                           || println!("{:?}", lock.try_read_for(Duration::from_millis(20)).err()));
+
+    // Should be Some(Recursion) (the lock doesn't support recursion), then 1:
+    {
+        let mut upgradeable = lock.upgradeable_read().unwrap();
+        println!("{:?}", lock.read().err());
+        *upgradeable.upgrade().unwrap() += 1;
+    }
+    println!("{}", *lock.read().unwrap());
+
+    // Should be 2 (read recursively), then Some(Recursion) (recursive writes never supported):
+    let recursive = ReaderWriterLock::with_recursion_policy(2,
+                                                            LockRecursionPolicy::SupportsRecursion);
+    let outer = recursive.read().unwrap();
+    let inner = recursive.read().unwrap();
+    println!("{}", *inner);
+    println!("{:?}", recursive.try_write_for(Duration::from_millis(20)).err());
+    drop(inner);
+    drop(outer);
+
+    // Should be Some(Recursion) (another upgradeable guard would alias the write lock), then 3:
+    {
+        let mut first = recursive.upgradeable_read().unwrap();
+        let second = recursive.upgradeable_read().unwrap();
+        println!("{:?}", first.upgrade().err());
+        drop(second);
+        *first.upgrade().unwrap() += 1;
+    }
+    println!("{}", *recursive.read().unwrap());
+
+    // Should be Some(Timeout { registry_name: "DemoRegistry" }):
+    let registry = FreezableLock::with_timeout("DemoRegistry", 0, Duration::from_millis(20));
+    while_locked_elsewhere(|| registry.write().unwrap(),
+                           || println!("{:?}", registry.write().err()));
+
+    println!();
+}
+
+fn while_locked_elsewhere<G>(lock: impl Fn() -> G + Sync, action: impl FnOnce())
+{
+    // Hold the lock on another thread while performing the action on this one.
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope|
+                  {
+                      scope.spawn(||
+                                  {
+                                      let _guard = lock();
+                                      sender.send(()).unwrap();
+                                      thread::sleep(Duration::from_millis(200));
+                                  });
+                      receiver.recv().unwrap();
+                      action();
+                  });
+}
+
+fn test_library_initialization()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Library initialization:");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should be true (no panics, as each library registers its types only once):
+    rdh::init();
+    rdh_extension_example::init();
+    rdh_third_party_example::init();
+    init_all(&[&rdh_third_party_example::TOGGLES, &rdh_third_party_example::SLIDERS]);
+    println!("{}", rdh_extension_example::LIBRARY.is_initialized());
+
+    // Should be ["Toggles", "Sliders"]:
+    println!("{:?}", rdh_third_party_example::LIBRARY.parents().iter().map(|parent| parent.name())
+                                                               .collect::<Vec<_>>());
+
+    // Should be Err(DependencyCycle { library_name: "CycleA" }), then false:
+    println!("{:?}", CYCLE_A.try_init());
+    println!("{}", CYCLE_A.is_initialized());
+
+    println!();
+}
+
+static CYCLE_A: Library = Library::new("CycleA", "0.1.0", 1, RDH_VERSION, &[&CYCLE_B], || {});
+static CYCLE_B: Library = Library::new("CycleB", "0.1.0", 2, RDH_VERSION, &[&CYCLE_A], || {});
+
+fn test_library_metadata()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Library metadata and version compatibility:");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should be rdh_extension_example 0.5.0:
+    let library_identifier = TypeRegistry::library_identifier_of::<Checkbox>();
+    if let Some(library_record) = TypeRegistry::library_record_of(library_identifier)
+    {
+        println!("{}", library_record);
+    }
+
+    // Should be true:
+    println!("{}", library_identifier == rdh_extension_example::LIBRARY.identifier());
+
+    // Should be ["rdh 0.5.0"]:
+    println!("{:?}", TypeRegistry::library_record_of(library_identifier).unwrap().parents().iter()
+                       .map(|parent| TypeRegistry::library_record_of(*parent).unwrap().to_string())
+                       .collect::<Vec<_>>());
+
+    // Should be 5 (rdh, rdh_extension_example, Toggles, Sliders, and rdh_third_party_example):
+    println!("{}", TypeRegistry::libraries().len());
+
+    // Should be 0.5.0:
+    println!("{}", TypeRegistry::library_record_of(rdh_third_party_example::SLIDERS.identifier())
+                     .map_or("None", |library_record| library_record.minimum_rdh_version()));
+
+    // Should be true:
+    println!("{}", matches!(TypeRegistry::try_register_library(LibraryRecord::new(
+                              library_identifier, "Impostor", "1.0.0", RDH_VERSION, Vec::new())),
+                            Err(RegistryError::DuplicateLibrary { .. })));
+
+    // Should be Indicated library (FromTheFuture) requires rdh 9.0.0 (or a compatible later
+    //  version), but rdh 0.5.0 is in use!
+    if let Err(error) = FROM_THE_FUTURE.try_init()
+    {
+        println!("{}", error);
+    }
+
+    // Should be true, then false (0.x minor versions are incompatible with one another):
+    println!("{}", matches!(FROM_THE_PAST.try_init(), Err(InitError::IncompatibleVersion { .. })));
+    println!("{}", FROM_THE_PAST.is_initialized());
+
+    // Should be This DowncastsLibraryTypesToIUIElement handler has already been registered for
+    //  the specified library (Sliders 0.5.0)!
+    if let Err(error) = IUIElementDowncaster::try_register_handler(
+                          rdh_third_party_example::SLIDERS.identifier(),
+                          Box::new(DowncastsSliderTypesToIUIElement{}))
+    {
+        println!("{}", error);
+    }
+
+    println!();
+}
+
+static FROM_THE_FUTURE: Library = Library::new("FromTheFuture", "1.0.0", 3, "9.0.0", &[], || {});
+static FROM_THE_PAST: Library = Library::new("FromThePast", "1.0.0", 4, "0.4.0", &[], || {});
+
+fn test_identifier_encoding()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("TypeIdentifier and LibraryIdentifier accessors, text, and byte encodings:");
+    println!("**********************************************************************************");
+    println!();
+
+    let checkbox_identifier = TypeRegistry::type_identifier_of::<Checkbox>();
+
+    // Should be 20792203623865871595846094854671916392, then
+    //  41377672462483076378035019486899738808:
+    println!("{}", checkbox_identifier.type_uuid());
+    println!("{}", checkbox_identifier.library_identifier().uuid());
+
+    // Should be 0fa46ec9-27fc-a74b-a732-0ea673cd5d68@1f210cc2-799f-4bd0-9669-ea80610818b8:
+    println!("{}", checkbox_identifier);
+
+    // Should be 1f210cc2-799f-4bd0-9669-ea80610818b8:
+    println!("{}", checkbox_identifier.library_identifier());
+
+    // Should be true, true, then true (hex digits of either case are accepted):
+    println!("{}", checkbox_identifier.to_string().parse::<TypeIdentifier>() ==
+                     Ok(checkbox_identifier));
+    println!("{}", "1F210CC2-799F-4BD0-9669-EA80610818B8".parse::<LibraryIdentifier>() ==
+                     Ok(checkbox_identifier.library_identifier()));
+    println!("{}", TypeIdentifier::new(checkbox_identifier.type_uuid(),
+                                       checkbox_identifier.library_identifier()) ==
+                     checkbox_identifier);
+
+    // Should be Err(InvalidLength { length: 35 }), then Err(InvalidCharacter { position: 8 }),
+    //  then Err(InvalidCharacter { position: 36 }):
+    println!("{:?}", "1f210cc2-799f-4bd0-9669-ea80610818b".parse::<LibraryIdentifier>());
+    println!("{:?}", "1f210cc2_799f-4bd0-9669-ea80610818b8".parse::<LibraryIdentifier>());
+    println!("{:?}", checkbox_identifier.to_string().replace('@', "#").parse::<TypeIdentifier>());
+
+    // Should be [15, 164, 110, 201] (the type UUID's leading bytes), then 32, then true:
+    let bytes = checkbox_identifier.to_bytes();
+    println!("{:?}", &bytes[..4]);
+    println!("{}", bytes.len());
+    println!("{}", TypeIdentifier::from_bytes(bytes) == checkbox_identifier);
+
+    // Should be 2 (identifiers may key ordered maps and sets):
+    let mut ordered_identifiers = BTreeSet::new();
+    ordered_identifiers.insert(TypeRegistry::type_identifier_of::<Slider>());
+    ordered_identifiers.insert(checkbox_identifier);
+    ordered_identifiers.insert(checkbox_identifier);
+    println!("{}", ordered_identifiers.len());
+
+    println!();
+}
+
+fn test_uuid_derivation()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("name_uuid!, uuid!, name_uuid(), and parse_uuid():");
+    println!("**********************************************************************************");
+    println!();
+
+    const DNS_NAMESPACE_UUID: u128 = uuid!("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    const EXTENSION_UUID: u128 = uuid!("1F210CC2-799F-4BD0-9669-EA80610818B8");
+
+    // Should be 2ed6657d-e927-568b-95e1-2665a8aea6a2 (RFC 4122's own example, matching Python's
+    //  uuid.uuid5(uuid.NAMESPACE_DNS, "www.example.com")):
+    println!("{}", LibraryIdentifier::from_uuid(name_uuid!(DNS_NAMESPACE_UUID, "www.example.com")));
+
+    // Should be e2fd7fb6-aa02-50f9-a4c5-0e850c63ce18, then true:
+    const CHECKBOX_NAME_UUID: u128 =
+      name_uuid!(EXTENSION_UUID, rdh_extension_example::_hierarchy::checkbox::Checkbox);
+    println!("{}", LibraryIdentifier::from_uuid(CHECKBOX_NAME_UUID));
+    println!("{}", CHECKBOX_NAME_UUID ==
+                     name_uuid(rdh_extension_example::LIBRARY.identifier().uuid(),
+                               "rdh_extension_example::_hierarchy::checkbox::Checkbox"));
+
+    // Should be 8f5b8167-982a-5f6c-bac7-cd3b595e6757, then 2d9075f7-23b8-5240-ad4e-0770bc948c71
+    //  (names spanning SHA-1 block boundaries):
+    println!("{}", LibraryIdentifier::from_uuid(name_uuid(EXTENSION_UUID, &"x".repeat(40))));
+    println!("{}", LibraryIdentifier::from_uuid(name_uuid(EXTENSION_UUID, &"a".repeat(200))));
+
+    // Should be Ok(41377672462483076378035019486899738808), then
+    //  Err(InvalidCharacter { position: 35 }):
+    println!("{:?}", parse_uuid("1f210cc2-799f-4bd0-9669-ea80610818b8"));
+    println!("{:?}", parse_uuid("1f210cc2-799f-4bd0-9669-ea80610818bg"));
+
+    println!();
+}
+
+fn test_type_manifest()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("TypeManifest export, loading, and drift detection:");
+    println!("**********************************************************************************");
+    println!();
+
+    let manifest = TypeManifest::capture();
+    let checkbox_identifier = TypeRegistry::type_identifier_of::<Checkbox>();
+    let checkbox_entry = manifest.entries().iter()
+                           .find(|entry| entry.type_identifier() == checkbox_identifier)
+                           .expect("Checkbox is missing from the manifest!");
+
+    // Should be true, then 3 (ICheckbox, IUIElement, and IConstruct):
+    println!("{}", checkbox_entry.parent() ==
+                     Some(TypeRegistry::type_identifier_of::<UIElement>()));
+    println!("{}", checkbox_entry.interfaces().len());
+
+    // Should be Ok([]), then true:
+    let path = std::env::temp_dir().join("rdh_types.manifest");
+    manifest.export(&path).expect("Unable to export the manifest!");
+    println!("{:?}", TypeManifest::drift_from_file(&path));
+    println!("{}", TypeManifest::load(&path) == Ok(manifest.clone()));
+    let _ = std::fs::remove_file(&path);
+
+    // Tamper with a copy of the manifest's text to stand in for the previous release's:
+    let slider_name = TypeRegistry::type_name_of::<Slider>();
+    let toggle_switch_name = TypeRegistry::type_name_of::<ToggleSwitch>();
+    let baseline_text: String =
+      manifest.to_string().lines()
+              .filter(|line| !line.ends_with(&format!("\t{}", toggle_switch_name)))
+              .map(|line| if line.ends_with(&format!("\t{}", slider_name))
+                          {
+                              line.replacen(&TypeRegistry::type_identifier_of::<Slider>()
+                                               .to_string()[..8], "00000000", 1)
+                          }
+                          else
+                          {
+                              line.replace("checkbox::Checkbox", "checkbox::CheckBox")
+                          })
+              .chain(std::iter::once(format!("concrete\t{}\t-\t-\tvehicles::Car",
+                                             TypeIdentifier::new(1,
+                                               LibraryIdentifier::from_uuid(1)))))
+              .map(|line| line + "\n")
+              .collect();
+    let baseline: TypeManifest = baseline_text.parse().expect("Unable to parse the manifest!");
+
+    // Should be (baseline entries in identifier order, then added entries; module paths and
+    //  identifiers abbreviated):
+    //  Removed vehicles::Car (00000000-...-000000000001@...) (breaking: true)
+    //  Changed the identifier of Slider from 00000000-f6e5-... to 76b51c5b-f6e5-...
+    //   (breaking: true)
+    //  Renamed CheckBox to Checkbox (0fa46ec9-...) (breaking: false)
+    //  Added ToggleSwitch (4a4c0215-...) (breaking: false)
+    for drift in manifest.drift_from(&baseline)
+    {
+        println!("{} (breaking: {})", drift, drift.is_breaking());
+    }
+
+    // Should be Err(Malformed { line: 2 }), then Err(Io { kind: NotFound }):
+    println!("{:?}", "# Comment\nconcrete\tnot-an-identifier\t-\t-\tCar".parse::<TypeManifest>());
+    println!("{:?}", TypeManifest::load(std::env::temp_dir().join("rdh_missing.manifest")));
+
+    println!();
+}
+
+fn test_registry_scopes()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("RegistryScope isolation:");
+    println!("**********************************************************************************");
+    println!();
+
+    let slider = Slider::new();
+    let slider_identifier = TypeRegistry::type_identifier_of::<Slider>();
+    {
+        let _scope = RegistryScope::enter();
+
+        // Should be true, then 0, then false (nothing is registered within a new scope):
+        println!("{}", RegistryScope::is_active());
+        println!("{}", TypeRegistry::types().len());
+        println!("{}", rdh_extension_example::LIBRARY.is_initialized());
+
+        // Should be true, then false (only the extension library and its parents are initialized):
+        rdh_extension_example::LIBRARY.init();
+        println!("{}", rdh_extension_example::LIBRARY.is_initialized());
+        println!("{}", rdh_third_party_example::SLIDERS.is_initialized());
+
+        // Should be true:
+        println!("{}", matches!(TypeRegistry::try_type_identifier_of::<Slider>(),
+                                Err(RegistryError::UnregisteredType { .. })));
+
+        // Should be true, then false, then false (Slider's library isn't registered here, so
+        //  casts and type queries fail softly):
+        println!("{}", matches!(IUIElementDowncaster::try_downcast(&slider as &dyn IConstruct)
+                                  .map_err(|error| error.reason()),
+                                Err(CastFailure::UnregisteredType { .. })));
+        println!("{}", slider.is(slider_identifier));
+        println!("{}", slider.is_a(TypeRegistry::type_identifier_of::<UIElement>()));
+
+        // Should be Ok(()), then 1 (a throwaway registration, visible only within this scope):
+        println!("{:?}", TypeRegistry::try_register_type::<Slider>(1, 2));
+        println!("{}", TypeRegistry::type_identifier_of::<Slider>().type_uuid());
+
+        // Should be true (Slider's library never registered an IUIElement downcast handler here):
+        println!("{}", matches!(IUIElementDowncaster::try_downcast(&slider as &dyn IConstruct)
+                                  .map_err(|error| error.reason()),
+                                Err(CastFailure::UnregisteredLibrary { .. })));
+
+        // Should be true (each scope may be frozen independently):
+        TypeRegistry::freeze();
+        println!("{}", TypeRegistry::is_frozen());
+    }
+
+    // Should be false, true, then false (the process-wide registries are unaffected):
+    println!("{}", RegistryScope::is_active());
+    println!("{}", TypeRegistry::type_identifier_of::<Slider>() == slider_identifier);
+    println!("{}", TypeRegistry::is_frozen());
+
+    // Should be true:
+    println!("{}", IUIElementDowncaster::try_downcast(&slider as &dyn IConstruct).is_ok());
+
+    println!();
+}
+
+fn test_registry_freezing()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("TypeRegistry::freeze() and is_frozen():");
+    println!("**********************************************************************************");
+    println!();
+
+    // Should be false:
+    println!("{}", TypeRegistry::is_frozen());
+
+    TypeRegistry::freeze();
+
+    // Should be true:
+    println!("{}", TypeRegistry::is_frozen());
+
+    // Should be Ok(()) (freezing again has no effect):
+    println!("{:?}", TypeRegistry::try_freeze());
+
+    // Should be Err(Frozen { registry_name: "TypeRegistry" }):
+    println!("{:?}", TypeRegistry::try_register_family::<PropertyFamily>(
+                       273402361406394585306962963938917012357,
+                       41377672462483076378035019486899738808));
+
+    // Should be Err(Frozen { registry_name: "IUIElementDowncaster" }):
+    println!("{:?}", IUIElementDowncaster::try_register_handler(
+                       TypeRegistry::library_identifier_of::<Construct>(),
+                       Box::new(DowncastsRdhExtensionTypesToIUIElement{})));
+
+    // Should be Ok(TypeIdentifier { type_uuid: 20792203623865871595846094854671916392, .. })
+    // (read without acquiring any lock):
+    println!("{:?}", TypeRegistry::try_type_identifier_of::<Checkbox>());
+
+    // Should be true (read without acquiring any lock):
+    let toggle_switch = ToggleSwitch::new();
+    println!("{}", (&toggle_switch as &dyn IConstruct).as_iui_element().is_some());
+
+    println!();
+}
+
+fn implements_iui_element(iconstruct: &dyn IConstruct) -> bool
+{
+    iconstruct.as_iui_element().is_some()
+}
+
+fn click_as_checkbox(iconstruct_mut: &mut dyn IConstruct) -> bool
+{
+    match iconstruct_mut.as_icheckbox_mut()
+    {
+        Some(icheckbox_mut) => { icheckbox_mut.on_click(); true },
+        None => false
+    }
+}
+
+fn test_non_generic_downcasts()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Downcasting &dyn IConstruct within non-generic functions:");
+    println!("**********************************************************************************");
+    println!();
+
+    let ui_element = UIElement::new();
+    let construct = Construct::new();
+
+    // Should be true:
+    println!("{}", implements_iui_element(&ui_element));
+
+    // Should be false:
+    println!("{}", implements_iui_element(&construct));
+
+    let mut checkbox = Checkbox::new();
+
+    // Should be on_click messages for Construct, UIElement, and Checkbox, followed by true:
+    println!("{}", click_as_checkbox(&mut checkbox));
+
+    let mut ui_element_mut = UIElement::new();
+
+    // Should be false:
+    println!("{}", click_as_checkbox(&mut ui_element_mut));
+
+    // Should be Processing a UIElement instance OF ANY KIND!, followed by on_click messages for
+    //  Construct, UIElement, and Checkbox:
+    dispatch_iconstruct_by_implemented_interface(&mut checkbox);
+
+    println!();
+}
+
+fn count_castable<U>(iconstructs: &[&dyn IConstruct]) -> usize where U: CastTarget + ?Sized
+{
+    iconstructs.iter().filter(|iconstruct| iconstruct.cast::<U>().is_some()).count()
+}
+
+fn test_generic_casts()
+{
+    println!();
+    println!("**********************************************************************************");
+    println!("Transmutes::cast::<dyn I>() and CastTarget:");
+    println!("**********************************************************************************");
+    println!();
+
+    let ui_element = UIElement::new();
+    let checkbox = Checkbox::new();
+    let iconstructs = [&ui_element as &dyn IConstruct, &checkbox as &dyn IConstruct];
+
+    // Should be true:
+    println!("{}", iconstructs[1].cast::<dyn ICheckbox>().is_some());
+
+    // Should be false:
+    println!("{}", iconstructs[0].cast::<dyn ICheckbox>().is_some());
+
+    // Should be 2:
+    println!("{}", count_castable::<dyn IUIElement>(&iconstructs));
+
+    // Should be 1:
+    println!("{}", count_castable::<dyn ICheckbox>(&iconstructs));
+
+    let mut checkbox_mut = Checkbox::new();
+    let iconstruct_mut = &mut checkbox_mut as &mut dyn IConstruct;
+
+    // Should be true:
+    println!("{}", iconstruct_mut.cast_mut::<dyn IUIElement>().is_some());
+
+    // Should be true:
+    println!("{}", iconstruct_mut.try_cast_mut::<dyn ICheckbox>().is_ok());
+
+    // Should be true:
+    let error = iconstructs[0].try_cast::<dyn ICheckbox>().err().unwrap();
+    println!("{}", error.reason() == CastFailure::NotTargetType);
+
+    println!();
 }
 
 fn main()
 {
     // Initialize the top layer of the Rust Development Hierarchy.
-    rdh_extension_example::init();
+    rdh_third_party_example::init();
 
     // Run, Tests, run!
     test_concrete_identifiers();
     test_concrete_implements();
     test_concrete_is();
+    test_concrete_is_a();
     test_divinator();
+    test_type_names();
+    test_generic_types();
+    test_registry_errors();
+    test_registry_enumeration();
+    test_registry_lineage();
+    test_necromancer_ancestors();
+    test_owned_casts();
+    test_cast_errors();
+    test_generic_casts();
+    test_non_generic_downcasts();
+    test_derived_type_registration();
+    test_third_party_types();
+    test_dispatch_tables();
+    test_cached_identifiers();
+    test_automatic_registration();
+    test_reader_writer_lock();
+    test_library_initialization();
+    test_library_metadata();
+    test_identifier_encoding();
+    test_uuid_derivation();
+    test_type_manifest();
+    test_registry_scopes();
+
+    // Must run last, as no registrations are accepted thereafter.
+    test_registry_freezing();
 }